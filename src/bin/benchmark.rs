@@ -0,0 +1,98 @@
+// plays every answer of the list with the solver, without a window, to compare the strategies
+
+use std::time::Instant;
+
+use game::engine::{
+    solver::{Solver, Strategy},
+    stats::GameStats,
+    Dictionary, GameEngine, GameOptions, GameState,
+};
+
+fn get_strategy(name: &str) -> Option<Strategy> {
+    match name {
+        "entropy" => Some(Strategy::Entropy),
+        "minimax" => Some(Strategy::Minimax),
+        _ => None,
+    }
+}
+
+// the number of guesses the solver needed, none if it lost
+fn solve(answer_index: usize, options: &GameOptions, strategy: Strategy) -> Option<usize> {
+    let mut engine = GameEngine::with_answer_index(answer_index, options);
+    while !engine.is_over() {
+        let guess = Solver::from_engine(&engine, strategy).suggest()?;
+        engine.submit_guess(&guess).ok()?;
+    }
+    (engine.get_game_state() == GameState::Won).then_some(engine.get_curr_row())
+}
+
+pub fn main() {
+    // the options of the benchmark are taken out, the rest are the options of the game
+    let mut strategy = Strategy::Entropy;
+    let mut limit: Option<usize> = None;
+    let mut game_args: Vec<String> = Vec::new();
+
+    let mut args = std::env::args();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-s" | "--strategy" => {
+                match args.next().as_deref().and_then(get_strategy) {
+                    Some(value) => strategy = value,
+                    None => eprintln!("The strategy must be entropy or minimax"),
+                }
+            },
+            "-n" | "--limit" => {
+                match args.next().and_then(|value| value.parse::<usize>().ok()) {
+                    Some(value) => limit = Some(value),
+                    None => eprintln!("The limit must be a number"),
+                }
+            },
+            _ => game_args.push(arg),
+        }
+    }
+    let options = GameOptions::from_args(game_args.into_iter());
+
+    let number_of_answers = Dictionary::get(options.word_length).get_answers().len();
+    let number_of_games = limit.map_or(number_of_answers, |limit| limit.min(number_of_answers));
+
+    println!("{:?} strategy, {} letters, {} guesses{}, {} of {} answers",
+        strategy, options.word_length, options.max_guesses, if options.hard_mode { ", hard mode" } else { "" },
+        number_of_games, number_of_answers);
+
+    // the same buckets as the statistics of the game
+    let mut stats = GameStats::new();
+    stats.games_won_by_attempt.resize(options.max_guesses, 0);
+    let mut lost: Vec<String> = Vec::new();
+
+    let start = Instant::now();
+    for answer_index in 0..number_of_games {
+        let attempt = solve(answer_index, &options, strategy);
+        stats.record_game(attempt, options.hard_mode, None);
+        if attempt.is_none() {
+            lost.push(Dictionary::get(options.word_length).get_answers()[answer_index].iter().collect());
+        }
+
+        if (answer_index + 1) % 50 == 0 {
+            eprintln!("{}/{}", answer_index + 1, number_of_games);
+        }
+    }
+
+    let games_won = stats.get_games_played() - stats.games_lost;
+    let total_guesses: u32 = stats.games_won_by_attempt.iter().enumerate()
+        .map(|(i, wins)| (i as u32 + 1) * wins)
+        .sum();
+
+    println!("Win rate: {:.2}%", stats.get_win_rate());
+    if games_won != 0 {
+        println!("Average guesses: {:.4}", total_guesses as f64 / games_won as f64);
+    }
+    println!("Guess distribution:");
+    for (i, wins) in stats.games_won_by_attempt.iter().enumerate() {
+        println!("  {}: {}", i + 1, wins);
+    }
+    println!("  X: {}", stats.games_lost);
+    if !lost.is_empty() {
+        println!("Lost: {}", lost.join(" "));
+    }
+    println!("Time: {:.2?}", start.elapsed());
+}
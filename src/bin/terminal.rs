@@ -0,0 +1,209 @@
+// the same game in a terminal, for when there is no display to open a window on
+
+use std::io::{self, BufRead, Write};
+
+use game::engine::{
+    assets, daily,
+    history::{self, GameRecord},
+    settings::Settings,
+    solver::{Solver, Strategy},
+    stats::GameStats,
+    GameEngine, GameMode, GameOptions, GameState, State,
+};
+
+const KEYBOARD_ROWS: [&str; 3] = ["QWERTYUIOP", "ASDFGHJKL", "ZXCVBNM"];
+
+// the colors of the window as 24-bit ansi backgrounds
+const GREEN: (u8, u8, u8) = (0, 153, 0);
+const YELLOW: (u8, u8, u8) = (153, 153, 0);
+const GRAY: (u8, u8, u8) = (89, 89, 89);
+const DARK_GRAY: (u8, u8, u8) = (37, 37, 37);
+const LIGHT_GRAY: (u8, u8, u8) = (116, 116, 116);
+
+fn paint(text: &str, (r, g, b): (u8, u8, u8)) -> String {
+    format!("\x1b[1;97;48;2;{};{};{}m{}\x1b[0m", r, g, b, text)
+}
+
+fn get_color_of_tile(state: State) -> (u8, u8, u8) {
+    match state {
+        State::NotFinalized | State::NotInWord => GRAY,
+        State::IncorrectInWord => YELLOW,
+        State::CorrectInWord => GREEN,
+    }
+}
+
+fn get_color_of_key(state: State) -> (u8, u8, u8) {
+    match state {
+        State::NotFinalized => LIGHT_GRAY,
+        State::NotInWord => DARK_GRAY,
+        State::IncorrectInWord => YELLOW,
+        State::CorrectInWord => GREEN,
+    }
+}
+
+fn draw_board(engine: &GameEngine) {
+    println!();
+    for row in 0..engine.get_max_guesses() {
+        let mut line = String::from("    ");
+        match engine.get_guesses().get(row) {
+            Some(guess) => {
+                let feedback = engine.get_feedback()[row];
+                for (i, ch) in guess.iter().enumerate() {
+                    line += &paint(&format!(" {} ", ch), get_color_of_tile(feedback.get_state(i)));
+                    line.push(' ');
+                }
+            },
+            None => {
+                for _ in 0..engine.get_word_length() {
+                    line += " _  ";
+                }
+            },
+        }
+        println!("{}", line);
+    }
+    println!();
+
+    // the keyboard of the window, every row moved a bit further to the right
+    for (i, keys) in KEYBOARD_ROWS.iter().enumerate() {
+        let mut line = " ".repeat(2 + 2 * i);
+        for ch in keys.chars() {
+            line += &paint(&format!(" {} ", ch), get_color_of_key(engine.get_letter_state(ch)));
+        }
+        println!("{}", line);
+    }
+    println!();
+}
+
+fn draw_stats(stats: &GameStats, is_daily: bool) {
+    println!("Games played: {}   Win rate: {}%   Hard mode wins: {}",
+        stats.get_games_played(), stats.get_win_rate().round(), stats.hard_mode_wins);
+    if is_daily {
+        println!("Daily streak: {}   Max daily streak: {}",
+            stats.get_daily_streak(daily::get_today()), stats.max_daily_streak);
+    }
+    else {
+        println!("Current streak: {}   Max streak: {}", stats.current_streak, stats.max_streak);
+    }
+
+    println!("Guess distribution:");
+    let most_wins = stats.games_won_by_attempt.iter().copied().max().unwrap_or(0).max(1);
+    for (i, wins) in stats.games_won_by_attempt.iter().enumerate() {
+        let bar_length = 1 + (*wins as usize * 30) / most_wins as usize;
+        println!("  {} {}", i + 1, paint(&format!("{:>width$} ", wins, width = bar_length), GRAY));
+    }
+}
+
+// counts the game in the same statistics and history as the window does
+fn record_game(engine: &GameEngine) -> GameStats {
+    let dir = assets::get_data_dir().unwrap_or_default();
+    let mut stats = GameStats::load(&dir, engine.get_word_length());
+
+    let attempt = (engine.get_game_state() == GameState::Won).then_some(engine.get_curr_row());
+    stats.record_game(attempt, engine.is_hard_mode(), engine.get_daily_date());
+    if stats.games_won_by_attempt.len() < engine.get_max_guesses() {
+        stats.games_won_by_attempt.resize(engine.get_max_guesses(), 0);
+    }
+
+    if let Err(err) = stats.save(&dir, engine.get_word_length()) {
+        eprintln!("Could not save the statistics: {}", err);
+    }
+    if let Err(err) = history::append_to_history(&dir, &GameRecord::from_engine(engine)) {
+        eprintln!("Could not save the game to the history: {}", err);
+    }
+    stats
+}
+
+fn print_remaining_answers(engine: &GameEngine, options: &GameOptions) {
+    if options.show_remaining && !engine.is_over() {
+        println!("{} words left\n", engine.get_number_of_remaining_answers());
+    }
+}
+
+// plays one game, returns false if the input ended before it was over
+fn play(options: &GameOptions, input: &mut impl Iterator<Item = String>) -> bool {
+    let mut engine = GameEngine::new(options);
+
+    println!("Guess the word of {} letters in {} tries{}.",
+        engine.get_word_length(), engine.get_max_guesses(), if engine.is_hard_mode() { " (hard mode)" } else { "" });
+    draw_board(&engine);
+    print_remaining_answers(&engine, options);
+
+    // today's daily game may already be over, it was counted when it was played
+    if engine.is_over() {
+        println!("Today's game is already over, the next one is tomorrow.\n");
+        let dir = assets::get_data_dir().unwrap_or_default();
+        draw_stats(&GameStats::load(&dir, engine.get_word_length()), engine.get_mode() == GameMode::Daily);
+        return true;
+    }
+
+    while !engine.is_over() {
+        print!("Guess {}/{}: ", engine.get_curr_row() + 1, engine.get_max_guesses());
+        let _ = io::stdout().flush();
+
+        let line = match input.next() {
+            Some(line) => line,
+            None => return false,
+        };
+        // with the hints on, a question mark asks the solver for the next guess
+        if options.hints && line.trim() == "?" {
+            match Solver::from_engine(&engine, Strategy::Entropy).suggest() {
+                Some(suggestion) => println!("Try {}", suggestion.iter().collect::<String>()),
+                None => println!("No word fits the colors"),
+            }
+            continue;
+        }
+
+        let guess: Vec<char> = line.trim().chars().collect();
+        match engine.submit_guess(&guess) {
+            Ok(_) => {
+                // today's guesses are kept so that the daily game cannot be played again
+                if let Some(date) = engine.get_daily_date() {
+                    daily::save_progress(&engine, date);
+                }
+                draw_board(&engine);
+                print_remaining_answers(&engine, options);
+            },
+            Err(err) => println!("{}", err),
+        }
+    }
+
+    let answer: String = engine.get_answer().iter().collect();
+    if engine.get_game_state() == GameState::Won {
+        println!("{}", paint(" YOU WON ", GREEN));
+    }
+    else {
+        println!("The word was {}", paint(&format!(" {} ", answer), DARK_GRAY));
+    }
+    if let Some(code) = engine.get_game_code() {
+        println!("Game code: {}", code);
+    }
+    println!();
+
+    let stats = record_game(&engine);
+    draw_stats(&stats, engine.get_mode() == GameMode::Daily);
+    true
+}
+
+pub fn main() {
+    // the same settings as the window, changed by the arguments of this start
+    let settings = Settings::load(&assets::get_data_dir().unwrap_or_default());
+    let mut options = settings.get_options().with_args(std::env::args());
+    let stdin = io::stdin();
+    let mut input = stdin.lock().lines().map_while(Result::ok);
+
+    loop {
+        if !play(&options, &mut input) {
+            println!();
+            return;
+        }
+
+        print!("\nPlay again? [y/N] ");
+        let _ = io::stdout().flush();
+        match input.next() {
+            Some(answer) if answer.trim().eq_ignore_ascii_case("y") => {},
+            _ => return,
+        }
+        // like the window, the next game after a daily one, a seed or a code is a random one
+        options.mode = GameMode::Random;
+    }
+}
@@ -0,0 +1,366 @@
+// the rules of the game, kept free of any ggez types so that a game can be played without opening a window
+
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use chrono::NaiveDate;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+pub mod assets;
+pub mod lua_wrapper;
+pub mod daily;
+mod dictionary;
+pub use self::dictionary::Dictionary;
+mod feedback;
+pub use self::feedback::{score, Feedback, MAX_WORD_LENGTH};
+mod game_code;
+pub mod history;
+pub use self::game_code::{get_game_code, parse_game_code};
+mod options;
+pub use self::options::{GameMode, GameOptions};
+pub mod saved_game;
+pub mod settings;
+pub mod solver;
+use self::solver::{Solver, Strategy};
+pub mod stats;
+
+pub const MIN_NUMBER_OF_GUESSES: usize = 4;
+pub const DEFAULT_NUMBER_OF_GUESSES: usize = 6;
+pub const MAX_NUMBER_OF_GUESSES: usize = 8;
+
+pub const MIN_WORD_LENGTH: usize = 4;
+pub const DEFAULT_WORD_LENGTH: usize = 5;
+pub const NUMBER_OF_WORD_LENGTHS: usize = MAX_WORD_LENGTH - MIN_WORD_LENGTH + 1;
+
+pub const WORDLE_LETTERS_COUNT: usize = 26;
+
+#[derive(Copy, Clone, Debug)]
+#[derive(PartialEq, PartialOrd)]
+pub enum State {
+    NotFinalized,
+    NotInWord,
+    IncorrectInWord,
+    CorrectInWord,
+}
+
+#[derive(Copy, Clone, Debug)]
+#[derive(PartialEq)]
+pub enum GameState {
+    NotOver,
+    Won,
+    Lost,
+}
+
+#[derive(Debug)]
+#[derive(PartialEq)]
+pub enum GuessError {
+    GameIsOver,
+    NotEnoughLetters,
+    TooManyLetters,
+    NotInWordList,
+    // hard mode: a letter revealed as green has to stay in its place
+    GreenNotKept { position: usize, letter: char },
+    // hard mode: a letter revealed as yellow or green has to be used again
+    LetterNotUsed(char),
+}
+
+impl fmt::Display for GuessError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GuessError::GameIsOver => write!(f, "The game is over"),
+            GuessError::NotEnoughLetters => write!(f, "Not enough letters"),
+            GuessError::TooManyLetters => write!(f, "Too many letters"),
+            GuessError::NotInWordList => write!(f, "Invalid word"),
+            GuessError::GreenNotKept { position, letter } => {
+                let ordinal_suffix = match position + 1 {
+                    1 => "st",
+                    2 => "nd",
+                    3 => "rd",
+                    _ => "th",
+                };
+                write!(f, "{}{} letter must be {}", position + 1, ordinal_suffix, letter)
+            },
+            GuessError::LetterNotUsed(letter) => write!(f, "Guess must contain {}", letter),
+        }
+    }
+}
+
+pub struct GameEngine {
+    answer: Vec<char>,
+    // where the answer is in the list of answers, if it came from there
+    answer_index: Option<usize>,
+    guesses: Vec<Vec<char>>,
+    feedback: Vec<Feedback>,
+    max_guesses: usize,
+    hard_mode: bool,
+    letter_states: [State; WORDLE_LETTERS_COUNT],
+    game_state: GameState,
+    mode: GameMode,
+    // set for the daily game, whose guesses are saved under this date after every one of them
+    daily_date: Option<NaiveDate>,
+    started_at: Instant,
+    // how long it took, once the game is over
+    time_played: Option<Duration>,
+}
+
+impl GameEngine {
+    pub fn new(options: &GameOptions) -> GameEngine {
+        match options.mode {
+            GameMode::Random => {
+                let number_of_words = Dictionary::get(options.word_length).get_answers().len();
+                GameEngine::with_answer_index(rand::thread_rng().gen_range(0..number_of_words), options)
+            },
+            GameMode::Daily => daily::start_daily_game(options),
+            GameMode::Seeded(seed) => {
+                let number_of_words = Dictionary::get(options.word_length).get_answers().len();
+                GameEngine::with_answer_index(ChaCha8Rng::seed_from_u64(seed).gen_range(0..number_of_words), options)
+            },
+            GameMode::Code(index) => GameEngine::with_answer_index(index, options),
+        }
+    }
+
+    // the answer is the word at the index of the list of answers of the length from the options
+    pub fn with_answer_index(index: usize, options: &GameOptions) -> GameEngine {
+        let answer = Dictionary::get(options.word_length).get_answers()[index].clone();
+        let mut engine = GameEngine::with_answer(answer, options);
+        engine.answer_index = Some(index);
+        engine
+    }
+
+    // the length of the word comes from the answer, the rest of the rules from the options
+    pub fn with_answer(answer: Vec<char>, options: &GameOptions) -> GameEngine {
+        GameEngine {
+            answer: answer.iter().map(|ch| ch.to_ascii_uppercase()).collect(),
+            answer_index: None,
+            guesses: Vec::with_capacity(options.max_guesses),
+            feedback: Vec::with_capacity(options.max_guesses),
+            max_guesses: options.max_guesses,
+            hard_mode: options.hard_mode,
+            letter_states: [State::NotFinalized; WORDLE_LETTERS_COUNT],
+            game_state: GameState::NotOver,
+            mode: options.mode,
+            daily_date: None,
+            started_at: Instant::now(),
+            time_played: None,
+        }
+    }
+
+    pub fn is_valid_word(word: &[char]) -> bool {
+        if !(MIN_WORD_LENGTH..=MAX_WORD_LENGTH).contains(&word.len()) {
+            return false;
+        }
+        let word: Vec<char> = word.iter().map(|ch| ch.to_ascii_uppercase()).collect();
+        Dictionary::get(word.len()).is_allowed(&word)
+    }
+
+    // checks the guess, records it and returns the state of the game after it
+    pub fn submit_guess(&mut self, guess: &[char]) -> Result<GameState, GuessError> {
+        self.make_guess(guess, self.hard_mode)
+    }
+
+    // the guesses of a game started before, which were checked against the hard mode when they were made
+    // (it may have been off back then)
+    pub fn replay_guesses(&mut self, guesses: &[Vec<char>]) {
+        for guess in guesses {
+            if let Err(err) = self.make_guess(guess, false) {
+                eprintln!("Could not replay the guess {}: {}", guess.iter().collect::<String>(), err);
+            }
+        }
+    }
+
+    fn make_guess(&mut self, guess: &[char], check_hints: bool) -> Result<GameState, GuessError> {
+        if self.is_over() {
+            return Err(GuessError::GameIsOver);
+        }
+        if guess.len() < self.get_word_length() {
+            return Err(GuessError::NotEnoughLetters);
+        }
+        if guess.len() > self.get_word_length() {
+            return Err(GuessError::TooManyLetters);
+        }
+        if !GameEngine::is_valid_word(guess) {
+            return Err(GuessError::NotInWordList);
+        }
+
+        let word: Vec<char> = guess.iter().map(|ch| ch.to_ascii_uppercase()).collect();
+        if check_hints {
+            self.check_revealed_hints(&word)?;
+        }
+
+        let feedback = score(&word, &self.answer);
+        for (i, ch) in word.iter().enumerate() {
+            let letter_state = &mut self.letter_states[GameEngine::get_letter_index(*ch)];
+            if *letter_state < feedback.get_state(i) {
+                *letter_state = feedback.get_state(i);
+            }
+        }
+        self.guesses.push(word);
+        self.feedback.push(feedback);
+
+        if feedback.is_solved() {
+            self.game_state = GameState::Won;
+        }
+        else if self.guesses.len() == self.max_guesses {
+            self.game_state = GameState::Lost;
+        }
+        if self.is_over() {
+            self.time_played = Some(self.started_at.elapsed());
+        }
+        Ok(self.game_state)
+    }
+
+    // in hard mode every guess has to keep the greens in place and use all the letters found so far
+    fn check_revealed_hints(&self, word: &[char]) -> Result<(), GuessError> {
+        for (guess, feedback) in self.guesses.iter().zip(&self.feedback) {
+            for (i, ch) in guess.iter().enumerate() {
+                if feedback.get_state(i) == State::CorrectInWord && word[i] != *ch {
+                    return Err(GuessError::GreenNotKept { position: i, letter: *ch });
+                }
+            }
+        }
+
+        for (guess, feedback) in self.guesses.iter().zip(&self.feedback) {
+            for ch in guess {
+                // a letter found twice has to be used twice
+                let times_found = guess.iter().enumerate()
+                    .filter(|(i, other)| *other == ch && feedback.get_state(*i) != State::NotInWord)
+                    .count();
+                let times_used = word.iter().filter(|other| *other == ch).count();
+                if times_used < times_found {
+                    return Err(GuessError::LetterNotUsed(*ch));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn get_letter_index(ch: char) -> usize {
+        (ch as u8 - b'A') as usize
+    }
+
+    pub fn get_answer(&self) -> &[char] {
+        &self.answer
+    }
+
+    // how many of the answers still agree with the colors of every guess
+    pub fn get_number_of_remaining_answers(&self) -> usize {
+        Solver::from_engine(self, Strategy::Entropy).get_number_of_candidates()
+    }
+
+    // the code to start the same game with, if the answer came from the list of answers
+    pub fn get_game_code(&self) -> Option<String> {
+        self.answer_index.map(|index| get_game_code(self.get_word_length(), index))
+    }
+
+    pub fn get_word_length(&self) -> usize {
+        self.answer.len()
+    }
+
+    pub fn is_hard_mode(&self) -> bool {
+        self.hard_mode
+    }
+
+    pub fn get_max_guesses(&self) -> usize {
+        self.max_guesses
+    }
+
+    pub fn get_guesses(&self) -> &[Vec<char>] {
+        &self.guesses
+    }
+
+    pub fn get_feedback(&self) -> &[Feedback] {
+        &self.feedback
+    }
+
+    pub fn get_letter_state(&self, ch: char) -> State {
+        self.letter_states[GameEngine::get_letter_index(ch.to_ascii_uppercase())]
+    }
+
+    pub fn get_mode(&self) -> GameMode {
+        self.mode
+    }
+
+    pub fn get_time_played(&self) -> Duration {
+        self.time_played.unwrap_or_else(|| self.started_at.elapsed())
+    }
+
+    pub fn get_daily_date(&self) -> Option<NaiveDate> {
+        self.daily_date
+    }
+
+    pub fn get_game_state(&self) -> GameState {
+        self.game_state
+    }
+
+    pub fn is_over(&self) -> bool {
+        self.game_state != GameState::NotOver
+    }
+
+    // the index of the row the next guess goes into
+    pub fn get_curr_row(&self) -> usize {
+        self.guesses.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chars(word: &str) -> Vec<char> {
+        word.chars().collect()
+    }
+
+    fn start_hard_game(answer: &str) -> GameEngine {
+        let options = GameOptions { hard_mode: true, ..GameOptions::default() };
+        GameEngine::with_answer(chars(answer), &options)
+    }
+
+    #[test]
+    fn hard_mode_keeps_greens_in_place() {
+        let mut engine = start_hard_game("CRANE");
+        engine.submit_guess(&chars("CRATE")).unwrap();
+        assert_eq!(engine.submit_guess(&chars("TRACE")), Err(GuessError::GreenNotKept { position: 0, letter: 'C' }));
+        assert_eq!(engine.get_curr_row(), 1);
+    }
+
+    #[test]
+    fn hard_mode_uses_yellows_again() {
+        let mut engine = start_hard_game("CRANE");
+        engine.submit_guess(&chars("NOTES")).unwrap();
+        assert_eq!(engine.submit_guess(&chars("SLATE")), Err(GuessError::LetterNotUsed('N')));
+        assert!(engine.submit_guess(&chars("CRANE")).is_ok());
+    }
+
+    #[test]
+    fn hard_mode_uses_a_letter_found_twice_twice() {
+        let mut engine = start_hard_game("LEVEL");
+        engine.submit_guess(&chars("EERIE")).unwrap();
+        assert_eq!(engine.submit_guess(&chars("BEING")), Err(GuessError::LetterNotUsed('E')));
+        assert!(engine.submit_guess(&chars("JEWEL")).is_ok());
+    }
+
+    #[test]
+    fn replayed_guesses_skip_the_hard_mode_check() {
+        let mut engine = start_hard_game("CRANE");
+        engine.replay_guesses(&[chars("CRATE"), chars("NOTES")]);
+        assert_eq!(engine.get_curr_row(), 2);
+        assert!(engine.is_hard_mode());
+        assert_eq!(engine.submit_guess(&chars("SLATE")), Err(GuessError::GreenNotKept { position: 0, letter: 'C' }));
+    }
+
+    #[test]
+    fn guess_of_another_length_is_refused() {
+        let mut engine = GameEngine::with_answer(chars("CRANE"), &GameOptions::default());
+        assert_eq!(engine.submit_guess(&chars("CRAN")), Err(GuessError::NotEnoughLetters));
+        assert_eq!(engine.submit_guess(&chars("CRANES")), Err(GuessError::TooManyLetters));
+        assert_eq!(engine.get_curr_row(), 0);
+    }
+
+    #[test]
+    fn hints_are_not_checked_out_of_hard_mode() {
+        let mut engine = GameEngine::with_answer(chars("CRANE"), &GameOptions::default());
+        engine.submit_guess(&chars("CRATE")).unwrap();
+        assert!(engine.submit_guess(&chars("NOTES")).is_ok());
+    }
+}
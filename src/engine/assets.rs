@@ -0,0 +1,83 @@
+use std::{borrow::Cow, fs, io::{self, Write}, path::{Path, PathBuf}};
+
+use directories::ProjectDirs;
+
+use super::MIN_WORD_LENGTH;
+
+// the default word lists are compiled into the program, so that it does not depend on the directory it is started from
+const WORD_FILES: [(&str, &str); 5] = [
+    ("four_letter_words", include_str!("../wordle/four_letter_words")),
+    ("five_letter_words", include_str!("../wordle/five_letter_words")),
+    ("six_letter_words", include_str!("../wordle/six_letter_words")),
+    ("seven_letter_words", include_str!("../wordle/seven_letter_words")),
+    ("eight_letter_words", include_str!("../wordle/eight_letter_words")),
+];
+
+const ANSWER_FILES: [(&str, &str); 5] = [
+    ("four_letter_answers", include_str!("../wordle/four_letter_answers")),
+    ("five_letter_answers", include_str!("../wordle/five_letter_answers")),
+    ("six_letter_answers", include_str!("../wordle/six_letter_answers")),
+    ("seven_letter_answers", include_str!("../wordle/seven_letter_answers")),
+    ("eight_letter_answers", include_str!("../wordle/eight_letter_answers")),
+];
+
+// the names ggez is started with, from which it makes the user data directory
+pub const GAME_ID: &str = "wordle";
+pub const AUTHOR: &str = "az";
+
+// where the files of the player are kept, e.g. ~/.local/share/wordle on Linux;
+// the same directory as the user data directory of ggez, so that it can also be found without a window
+pub fn get_data_dir() -> Option<PathBuf> {
+    ProjectDirs::from("", AUTHOR, GAME_ID).map(|dirs| dirs.data_local_dir().to_path_buf())
+}
+
+// the contents go to a temporary file first, which then takes the place of the old one,
+// so that a crash in the middle of writing leaves the old file as it was
+pub fn write_atomically(path: &Path, contents: &str) -> io::Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let temp_path = path.with_extension("tmp");
+    // synced through the handle it was written with, a read-only one cannot be flushed on Windows
+    let mut temp_file = fs::File::create(&temp_path)?;
+    temp_file.write_all(contents.as_bytes())?;
+    temp_file.sync_all()?;
+    drop(temp_file);
+    fs::rename(&temp_path, path)
+}
+
+// the directory in which a word list with the name of a default one replaces it
+pub fn get_word_lists_dir() -> Option<PathBuf> {
+    get_data_dir().map(|dir| dir.join("words"))
+}
+
+// every word that is accepted as a guess, one on every line
+pub fn get_word_list(word_length: usize) -> Cow<'static, str> {
+    get_file(WORD_FILES[word_length - MIN_WORD_LENGTH], word_length)
+}
+
+// the common words the answer is picked from, one on every line
+pub fn get_answer_list(word_length: usize) -> Cow<'static, str> {
+    get_file(ANSWER_FILES[word_length - MIN_WORD_LENGTH], word_length)
+}
+
+// a replaced list without a single word of the length would leave the game without answers,
+// so the default one is kept then
+fn get_file((file_name, default_contents): (&str, &'static str), word_length: usize) -> Cow<'static, str> {
+    let overridden = get_word_lists_dir()
+        .and_then(|dir| fs::read_to_string(dir.join(file_name)).ok());
+
+    match overridden {
+        Some(contents) if has_word_of_length(&contents, word_length) => Cow::Owned(contents),
+        Some(_) => {
+            eprintln!("The word list {} has no words of {} letters, using the default one", file_name, word_length);
+            Cow::Borrowed(default_contents)
+        },
+        None => Cow::Borrowed(default_contents),
+    }
+}
+
+// the same lines as the ones taken by get_all_words of words.lua
+fn has_word_of_length(contents: &str, word_length: usize) -> bool {
+    contents.lines().any(|line| line.len() == word_length && line.chars().all(|ch| ch.is_ascii_alphabetic()))
+}
@@ -0,0 +1,101 @@
+use std::{fs, path::PathBuf};
+
+use chrono::{Local, NaiveDate};
+use rand::{seq::SliceRandom, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+use super::{assets, Dictionary, GameEngine, GameOptions, MIN_NUMBER_OF_GUESSES, MAX_NUMBER_OF_GUESSES};
+
+// changing any of these changes the answer of every day
+const DAILY_SEED: u64 = 0x5744_4c45;
+const FIRST_DAY: (i32, u32, u32) = (2024, 1, 1);
+
+pub fn get_today() -> NaiveDate {
+    Local::now().date_naive()
+}
+
+// the number of the puzzle, counted from the first day
+pub fn get_day_number(date: NaiveDate) -> i64 {
+    let first_day = NaiveDate::from_ymd_opt(FIRST_DAY.0, FIRST_DAY.1, FIRST_DAY.2).unwrap();
+    (date - first_day).num_days()
+}
+
+// the index of the answer in the list of answers: the words are shuffled once by the fixed seed
+// and every day takes the next one, starting over when all of them were used
+pub fn get_daily_answer_index(word_length: usize, date: NaiveDate) -> usize {
+    let mut order: Vec<usize> = (0..Dictionary::get(word_length).get_answers().len()).collect();
+    let mut rng = ChaCha8Rng::seed_from_u64(DAILY_SEED + word_length as u64);
+    order.shuffle(&mut rng);
+
+    order[get_day_number(date).rem_euclid(order.len() as i64) as usize]
+}
+
+// next to the statistics in the user data directory
+fn get_file_path(word_length: usize) -> PathBuf {
+    assets::get_data_dir().unwrap_or_default().join(format!("daily_{}", word_length))
+}
+
+// the daily game of a date as far as it was played
+struct DailyProgress {
+    max_guesses: usize,
+    hard_mode: bool,
+    guesses: Vec<Vec<char>>,
+}
+
+// the rules and the guesses of the daily game of that date; a day that was not played yet
+// (or was saved without its rules) gets the rules of the options
+fn load_progress(options: &GameOptions, date: NaiveDate) -> DailyProgress {
+    let mut progress = DailyProgress {
+        max_guesses: options.max_guesses,
+        hard_mode: options.hard_mode,
+        guesses: Vec::new(),
+    };
+    let contents = match fs::read_to_string(get_file_path(options.word_length)) {
+        Ok(contents) => contents,
+        Err(_err) => return progress,
+    };
+    let mut lines = contents.lines();
+    let mut first_line = lines.next().unwrap_or_default().split_whitespace();
+    if first_line.next() != Some(date.to_string().as_str()) {
+        return progress;
+    }
+
+    if let Some(max_guesses) = first_line.next().and_then(|value| value.parse::<usize>().ok()) {
+        if (MIN_NUMBER_OF_GUESSES..=MAX_NUMBER_OF_GUESSES).contains(&max_guesses) {
+            progress.max_guesses = max_guesses;
+        }
+    }
+    if let Some(hard_mode) = first_line.next().and_then(|value| value.parse::<bool>().ok()) {
+        progress.hard_mode = hard_mode;
+    }
+    progress.guesses = lines.map(|line| line.chars().collect()).collect();
+    progress
+}
+
+// the date and the rules the game was started with on the first line and a guess on every next one
+pub fn save_progress(engine: &GameEngine, date: NaiveDate) {
+    let mut contents = format!("{} {} {}", date, engine.get_max_guesses(), engine.is_hard_mode());
+    for guess in engine.get_guesses() {
+        contents.push('\n');
+        contents.extend(guess.iter());
+    }
+    if let Err(err) = assets::write_atomically(&get_file_path(engine.get_word_length()), &contents) {
+        eprintln!("Could not save the daily game: {}", err);
+    }
+}
+
+// today's game together with the guesses already made today, so that it cannot be played twice;
+// once started, the day keeps its rules whatever the options are, so a finished day stays finished
+pub fn start_daily_game(options: &GameOptions) -> GameEngine {
+    let today = get_today();
+    let progress = load_progress(options, today);
+    let options = GameOptions {
+        max_guesses: progress.max_guesses,
+        hard_mode: progress.hard_mode,
+        ..options.clone()
+    };
+    let mut engine = GameEngine::with_answer_index(get_daily_answer_index(options.word_length, today), &options);
+    engine.replay_guesses(&progress.guesses);
+    engine.daily_date = Some(today);
+    engine
+}
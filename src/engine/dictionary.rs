@@ -0,0 +1,55 @@
+use std::collections::HashSet;
+use std::sync::OnceLock;
+
+use super::{lua_wrapper, MIN_WORD_LENGTH, NUMBER_OF_WORD_LENGTHS};
+
+// the words of one length, read from the files once and then shared by the whole program
+pub struct Dictionary {
+    answers: Vec<Vec<char>>,
+    allowed_guesses: Vec<Vec<char>>,
+    lookup: HashSet<Vec<char>>,
+}
+
+static DICTIONARIES: [OnceLock<Dictionary>; NUMBER_OF_WORD_LENGTHS] = [const { OnceLock::new() }; NUMBER_OF_WORD_LENGTHS];
+
+impl Dictionary {
+    // the words are read the first time a length is asked for, which is when its first game is set up
+    pub fn get(word_length: usize) -> &'static Dictionary {
+        DICTIONARIES[word_length - MIN_WORD_LENGTH].get_or_init(|| Dictionary::load(word_length))
+    }
+
+    fn load(word_length: usize) -> Dictionary {
+        let word_lists = lua_wrapper::get_word_lists(word_length);
+        let to_chars = |word: &String| -> Vec<char> { word.to_uppercase().chars().collect() };
+
+        let answers: Vec<Vec<char>> = word_lists.answers.iter().map(to_chars).collect();
+        let mut allowed_guesses: Vec<Vec<char>> = word_lists.allowed_guesses.iter().map(to_chars).collect();
+        let mut lookup: HashSet<Vec<char>> = allowed_guesses.iter().cloned().collect();
+
+        // every answer can also be guessed
+        for answer in &answers {
+            if lookup.insert(answer.clone()) {
+                allowed_guesses.push(answer.clone());
+            }
+        }
+
+        Dictionary {
+            answers,
+            allowed_guesses,
+            lookup,
+        }
+    }
+
+    pub fn get_answers(&self) -> &[Vec<char>] {
+        &self.answers
+    }
+
+    pub fn get_allowed_guesses(&self) -> &[Vec<char>] {
+        &self.allowed_guesses
+    }
+
+    // the word has to be in upper case
+    pub fn is_allowed(&self, word: &[char]) -> bool {
+        self.lookup.contains(word)
+    }
+}
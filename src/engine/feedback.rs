@@ -0,0 +1,180 @@
+use super::State;
+
+// no word of the game is longer than this, so the scoring can work on arrays instead of allocating
+pub const MAX_WORD_LENGTH: usize = 8;
+
+// the colors of a whole guess packed as a base-3 number, the first letter being the least significant digit:
+// 0 for a letter not in the word, 1 for a letter in the wrong place and 2 for a letter in the right place
+#[derive(Copy, Clone, Debug)]
+#[derive(PartialEq, Eq, Hash)]
+pub struct Feedback {
+    pattern: u16,
+    length: u8,
+}
+
+impl Feedback {
+    pub fn from_states(states: &[State]) -> Feedback {
+        let mut pattern: u16 = 0;
+        for state in states.iter().rev() {
+            pattern = pattern * 3 + Feedback::get_digit(*state);
+        }
+        Feedback {
+            pattern,
+            length: states.len() as u8,
+        }
+    }
+
+    fn get_digit(state: State) -> u16 {
+        match state {
+            State::NotFinalized | State::NotInWord => 0,
+            State::IncorrectInWord => 1,
+            State::CorrectInWord => 2,
+        }
+    }
+
+    fn get_state_of_digit(digit: u16) -> State {
+        match digit {
+            0 => State::NotInWord,
+            1 => State::IncorrectInWord,
+            _ => State::CorrectInWord,
+        }
+    }
+
+    // the number of different patterns a word of the given length can get
+    pub fn get_number_of_patterns(length: usize) -> usize {
+        3_usize.pow(length as u32)
+    }
+
+    pub fn get_pattern(&self) -> u16 {
+        self.pattern
+    }
+
+    pub fn get_length(&self) -> usize {
+        self.length as usize
+    }
+
+    pub fn get_state(&self, index: usize) -> State {
+        Feedback::get_state_of_digit(self.pattern / 3_u16.pow(index as u32) % 3)
+    }
+
+    pub fn get_states(&self) -> Vec<State> {
+        (0..self.get_length()).map(|i| self.get_state(i)).collect()
+    }
+
+    pub fn is_solved(&self) -> bool {
+        self.pattern as usize == Feedback::get_number_of_patterns(self.get_length()) - 1
+    }
+}
+
+// the colors the guess gets against the answer; both have to be of the same length and in the same case
+pub fn score(guess: &[char], answer: &[char]) -> Feedback {
+    let length = guess.len();
+    let mut states: [State; MAX_WORD_LENGTH] = [State::NotFinalized; MAX_WORD_LENGTH];
+
+    let mut indedxes_of_taken_letters_of_answer: [bool; MAX_WORD_LENGTH] = [false; MAX_WORD_LENGTH];
+    let mut indedxes_of_taken_letters_of_guess: [bool; MAX_WORD_LENGTH] = [false; MAX_WORD_LENGTH];
+
+    // searching for the greens in both
+    for i in 0..length {
+        if guess[i] == answer[i] {
+            states[i] = State::CorrectInWord;
+            indedxes_of_taken_letters_of_guess[i] = true;
+            indedxes_of_taken_letters_of_answer[i] = true;
+        }
+    }
+
+    // searching for the grays in guess
+    for i in 0..length {
+        if !answer.contains(&guess[i]) {
+            states[i] = State::NotInWord;
+            indedxes_of_taken_letters_of_guess[i] = true;
+        }
+    }
+
+    // searching for the yellows in both
+    for i in 0..length {
+        if indedxes_of_taken_letters_of_guess[i] {
+            continue;
+        }
+        let position_in_answer = (0..length)
+            .find(|&j| guess[i] == answer[j] && !indedxes_of_taken_letters_of_answer[j]);
+        if let Some(j) = position_in_answer {
+            states[i] = State::IncorrectInWord;
+            indedxes_of_taken_letters_of_guess[i] = true;
+            indedxes_of_taken_letters_of_answer[j] = true;
+        }
+    }
+
+    // searching for the missed (repeating) letters
+    for i in 0..length {
+        if !indedxes_of_taken_letters_of_guess[i] {
+            states[i] = State::NotInWord;
+            indedxes_of_taken_letters_of_guess[i] = true;
+        }
+    }
+
+    Feedback::from_states(&states[..length])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chars(word: &str) -> Vec<char> {
+        word.chars().collect()
+    }
+
+    // G for green, Y for yellow and . for gray
+    fn states(colors: &str) -> Vec<State> {
+        colors.chars()
+            .map(|color| match color {
+                'G' => State::CorrectInWord,
+                'Y' => State::IncorrectInWord,
+                _ => State::NotInWord,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn repeated_letter_of_guess_is_yellow_only_as_many_times_as_in_answer() {
+        assert_eq!(score(&chars("SPEED"), &chars("ABIDE")).get_states(), states("..Y.Y"));
+    }
+
+    #[test]
+    fn green_takes_the_letter_before_yellow() {
+        assert_eq!(score(&chars("EERIE"), &chars("THEME")).get_states(), states("Y...G"));
+    }
+
+    #[test]
+    fn repeated_letter_of_answer_gives_green_and_yellow() {
+        assert_eq!(score(&chars("ABBEY"), &chars("BOBBY")).get_states(), states(".YG.G"));
+        assert_eq!(score(&chars("BBBBB"), &chars("BOBBY")).get_states(), states("G.GG."));
+    }
+
+    #[test]
+    fn states_go_through_the_pattern_unchanged() {
+        for length in 4..=MAX_WORD_LENGTH {
+            for pattern in 0..Feedback::get_number_of_patterns(length) {
+                let digits: Vec<State> = (0..length)
+                    .map(|i| Feedback::get_state_of_digit((pattern / 3_usize.pow(i as u32) % 3) as u16))
+                    .collect();
+                let feedback = Feedback::from_states(&digits);
+                assert_eq!(feedback.get_pattern() as usize, pattern);
+                assert_eq!(feedback.get_length(), length);
+                assert_eq!(feedback.get_states(), digits);
+            }
+        }
+    }
+
+    #[test]
+    fn only_the_answer_itself_solves_the_game() {
+        for word in ["LOVE", "CRANE", "PLANET", "PICTURE", "ABSOLUTE"] {
+            let word = chars(word);
+            assert!(score(&word, &word).is_solved());
+
+            let mut other = word.clone();
+            other[0] = 'Z';
+            assert!(!score(&other, &word).is_solved());
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use super::{MIN_WORD_LENGTH, NUMBER_OF_WORD_LENGTHS};
+
+// base32 without the padding, read from the most significant digit
+const ALPHABET: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+
+// a short code of the answer, from which the same game can be started again;
+// the word length is in the lowest digits so that the code alone is enough
+pub fn get_game_code(word_length: usize, answer_index: usize) -> String {
+    let mut value = answer_index * NUMBER_OF_WORD_LENGTHS + (word_length - MIN_WORD_LENGTH);
+
+    let mut code: Vec<u8> = Vec::new();
+    loop {
+        code.push(ALPHABET[value % 32]);
+        value /= 32;
+        if value == 0 {
+            break;
+        }
+    }
+    code.iter().rev().map(|digit| *digit as char).collect()
+}
+
+// the word length and the index of the answer, if the code is valid
+pub fn parse_game_code(code: &str) -> Option<(usize, usize)> {
+    if code.is_empty() {
+        return None;
+    }
+
+    let mut value: usize = 0;
+    for ch in code.chars() {
+        let digit = ALPHABET.iter().position(|other| *other as char == ch.to_ascii_uppercase())?;
+        value = value.checked_mul(32)?.checked_add(digit)?;
+    }
+    Some((value % NUMBER_OF_WORD_LENGTHS + MIN_WORD_LENGTH, value / NUMBER_OF_WORD_LENGTHS))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::MAX_WORD_LENGTH;
+
+    #[test]
+    fn code_gives_back_the_word_length_and_the_answer() {
+        for word_length in MIN_WORD_LENGTH..=MAX_WORD_LENGTH {
+            for answer_index in [0, 1, 31, 32, 1000, 12345] {
+                let code = get_game_code(word_length, answer_index);
+                assert_eq!(parse_game_code(&code), Some((word_length, answer_index)));
+                assert_eq!(parse_game_code(&code.to_ascii_lowercase()), Some((word_length, answer_index)));
+            }
+        }
+    }
+
+    #[test]
+    fn invalid_codes_are_refused() {
+        assert_eq!(parse_game_code(""), None);
+        assert_eq!(parse_game_code("AB1"), None);
+        assert_eq!(parse_game_code("A-B"), None);
+        assert_eq!(parse_game_code(&"7".repeat(20)), None);
+    }
+}
@@ -0,0 +1,104 @@
+use std::{fs::{self, OpenOptions}, io::{self, Read, Seek, SeekFrom, Write}, path::Path};
+
+use chrono::{Local, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+use super::{GameEngine, GameMode, GameState};
+
+// every finished game is a line of json at the end of the file, which is never rewritten
+const HISTORY_FILE_NAME: &str = "history.jsonl";
+
+#[derive(Clone, Debug)]
+#[derive(Serialize, Deserialize)]
+pub struct GameRecord {
+    // when the game ended, in the rfc 3339 format
+    pub timestamp: String,
+    pub word_length: usize,
+    pub max_guesses: usize,
+    pub answer: String,
+    pub guesses: Vec<String>,
+    // the base-3 pattern of every guess
+    pub feedback: Vec<u16>,
+    pub mode: GameMode,
+    // the day of the puzzle, for a daily game (missing in the records from before the streaks)
+    #[serde(default)]
+    pub daily_date: Option<NaiveDate>,
+    pub hard_mode: bool,
+    pub game_code: Option<String>,
+    pub duration_secs: f64,
+    pub won: bool,
+}
+
+impl GameRecord {
+    // the record of a game that is over
+    pub fn from_engine(engine: &GameEngine) -> GameRecord {
+        GameRecord {
+            timestamp: Local::now().to_rfc3339(),
+            word_length: engine.get_word_length(),
+            max_guesses: engine.get_max_guesses(),
+            answer: engine.get_answer().iter().collect(),
+            guesses: engine.get_guesses().iter().map(|guess| guess.iter().collect()).collect(),
+            feedback: engine.get_feedback().iter().map(|feedback| feedback.get_pattern()).collect(),
+            mode: engine.get_mode(),
+            daily_date: engine.get_daily_date(),
+            hard_mode: engine.is_hard_mode(),
+            game_code: engine.get_game_code(),
+            duration_secs: engine.get_time_played().as_secs_f64(),
+            won: engine.get_game_state() == GameState::Won,
+        }
+    }
+}
+
+pub fn append_to_history(dir: &Path, record: &GameRecord) -> io::Result<()> {
+    fs::create_dir_all(dir)?;
+    let mut line = serde_json::to_string(record)?;
+    line.push('\n');
+
+    let mut file = OpenOptions::new()
+        .read(true)
+        .append(true)
+        .create(true)
+        .open(dir.join(HISTORY_FILE_NAME))?;
+
+    // a crash in the middle of writing can leave half of a line behind, which is ended first
+    // so that this record goes on a line of its own instead of being lost with it
+    if file.metadata()?.len() != 0 {
+        let mut last_byte = [0u8];
+        file.seek(SeekFrom::End(-1))?;
+        file.read_exact(&mut last_byte)?;
+        if last_byte[0] != b'\n' {
+            line.insert(0, '\n');
+        }
+    }
+    file.write_all(line.as_bytes())
+}
+
+// every game ever recorded, from the oldest; a broken line is skipped rather than losing the rest
+pub fn load_history(dir: &Path) -> Vec<GameRecord> {
+    let contents = fs::read_to_string(dir.join(HISTORY_FILE_NAME)).unwrap_or_default();
+    contents.lines()
+        .filter_map(|line| serde_json::from_str::<GameRecord>(line).ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::GameOptions;
+
+    #[test]
+    fn record_after_a_torn_line_is_kept() {
+        let dir = std::env::temp_dir().join("wordle_test_history_torn_line");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join(HISTORY_FILE_NAME), r#"{"timestamp": "2024-01-01T00:00:00+00:00", "word_len"#).unwrap();
+
+        let mut engine = GameEngine::with_answer("CRANE".chars().collect(), &GameOptions::default());
+        engine.submit_guess(&"CRANE".chars().collect::<Vec<char>>()).unwrap();
+        append_to_history(&dir, &GameRecord::from_engine(&engine)).unwrap();
+        append_to_history(&dir, &GameRecord::from_engine(&engine)).unwrap();
+
+        assert_eq!(load_history(&dir).len(), 2);
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
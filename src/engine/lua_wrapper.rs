@@ -0,0 +1,43 @@
+use rlua::{ Function, Lua, Table, Value };
+
+use super::assets;
+
+// compiled into the program together with the word lists
+const LUA_SCRIPT: &str = include_str!("../wordle/words.lua");
+
+// the answer is picked from the shorter list of common words, while a guess can be any word of the longer one
+pub struct WordLists {
+    pub answers: Vec<String>,
+    pub allowed_guesses: Vec<String>,
+}
+
+pub fn get_word_lists(word_length: usize) -> WordLists {
+    let lua = Lua::new();
+
+    // defined outside the context scope
+    let mut result = WordLists {
+        answers: Vec::new(),
+        allowed_guesses: Vec::new(),
+    };
+
+    lua.context(|lua_ctx| {
+        lua_ctx.load(LUA_SCRIPT).exec().unwrap();
+        let lua_function: Function = lua_ctx.globals().get("get_all_words").unwrap();
+        result.answers = get_table_of_words(&lua_function, &assets::get_answer_list(word_length), word_length);
+        result.allowed_guesses = get_table_of_words(&lua_function, &assets::get_word_list(word_length), word_length);
+    });
+    result
+}
+
+fn get_table_of_words(lua_function: &Function, text: &str, word_length: usize) -> Vec<String> {
+    let table: Table = lua_function.call((text, word_length)).unwrap();
+
+    let mut result: Vec<String> = Vec::new();
+    let mut ctr: i64 = 1;
+
+    while let Ok(value) = table.get::<Value, String>(rlua::Value::Integer(ctr)) {
+        result.push(value);
+        ctr += 1;
+    }
+    result
+}
@@ -0,0 +1,112 @@
+use serde::{Deserialize, Serialize};
+
+use super::{parse_game_code, Dictionary};
+use super::{MIN_WORD_LENGTH, MAX_WORD_LENGTH, DEFAULT_WORD_LENGTH};
+use super::{MIN_NUMBER_OF_GUESSES, MAX_NUMBER_OF_GUESSES, DEFAULT_NUMBER_OF_GUESSES};
+
+// where the answer of the game comes from
+#[derive(Copy, Clone, Debug)]
+#[derive(PartialEq, Serialize, Deserialize)]
+pub enum GameMode {
+    Random,
+    // the same answer for everyone on the same day
+    Daily,
+    // the answer is picked by a random generator started from this seed
+    Seeded(u64),
+    // the answer at this index of the list of answers, as read from a game code
+    Code(usize),
+}
+
+// how the next game is set up, chosen at startup or in the settings
+#[derive(Clone, Debug)]
+pub struct GameOptions {
+    pub word_length: usize,
+    pub max_guesses: usize,
+    pub hard_mode: bool,
+    pub mode: GameMode,
+    // a button that types the guess of the solver
+    pub hints: bool,
+    // the number of answers that still agree with the colors, shown next to the board
+    pub show_remaining: bool,
+    // the name of a built-in theme of the window or of a theme file
+    pub theme: String,
+    pub animations: bool,
+    pub sound: bool,
+}
+
+impl GameOptions {
+    // reads the options from command line arguments such as `--length 6 --guesses 8 --hard --daily`,
+    // a game code also sets the word length, so it wins over `--length`
+    pub fn from_args(args: impl Iterator<Item = String>) -> GameOptions {
+        GameOptions::default().with_args(args)
+    }
+
+    // the same, but the options that are not in the arguments are kept as they are (e.g. the saved settings)
+    pub fn with_args(self, args: impl Iterator<Item = String>) -> GameOptions {
+        let mut options = self;
+        let mut args = args.skip(1);
+        let mut code: Option<String> = None;
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "-l" | "--length" => {
+                    match args.next().and_then(|value| value.parse::<usize>().ok()) {
+                        Some(length) if (MIN_WORD_LENGTH..=MAX_WORD_LENGTH).contains(&length) => options.word_length = length,
+                        _ => eprintln!("The word length must be between {} and {}", MIN_WORD_LENGTH, MAX_WORD_LENGTH),
+                    }
+                },
+                "-g" | "--guesses" => {
+                    match args.next().and_then(|value| value.parse::<usize>().ok()) {
+                        Some(guesses) if (MIN_NUMBER_OF_GUESSES..=MAX_NUMBER_OF_GUESSES).contains(&guesses) => options.max_guesses = guesses,
+                        _ => eprintln!("The number of guesses must be between {} and {}", MIN_NUMBER_OF_GUESSES, MAX_NUMBER_OF_GUESSES),
+                    }
+                },
+                "--hard" => options.hard_mode = true,
+                "--hints" => options.hints = true,
+                "--remaining" => options.show_remaining = true,
+                "--daily" => options.mode = GameMode::Daily,
+                "--seed" => {
+                    match args.next().and_then(|value| value.parse::<u64>().ok()) {
+                        Some(seed) => options.mode = GameMode::Seeded(seed),
+                        None => eprintln!("The seed must be a non-negative number"),
+                    }
+                },
+                "-c" | "--code" => code = args.next(),
+                "--theme" => {
+                    match args.next() {
+                        Some(theme) => options.theme = theme,
+                        None => eprintln!("The theme must be given a name"),
+                    }
+                },
+                _ => eprintln!("Unknown argument: {}", arg),
+            }
+        }
+
+        if let Some(code) = code {
+            match parse_game_code(&code) {
+                Some((length, index)) if index < Dictionary::get(length).get_answers().len() => {
+                    options.word_length = length;
+                    options.mode = GameMode::Code(index);
+                },
+                _ => eprintln!("Invalid game code: {}", code),
+            }
+        }
+        options
+    }
+}
+
+impl Default for GameOptions {
+    fn default() -> GameOptions {
+        GameOptions {
+            word_length: DEFAULT_WORD_LENGTH,
+            max_guesses: DEFAULT_NUMBER_OF_GUESSES,
+            hard_mode: false,
+            mode: GameMode::Random,
+            hints: false,
+            show_remaining: false,
+            theme: String::from("dark"),
+            animations: true,
+            sound: false,
+        }
+    }
+}
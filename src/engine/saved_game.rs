@@ -0,0 +1,130 @@
+use std::{fs, io, path::Path, time::{Duration, Instant}};
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use super::{assets, GameEngine, GameMode, GameOptions, MIN_WORD_LENGTH, MAX_WORD_LENGTH};
+use super::{MIN_NUMBER_OF_GUESSES, MAX_NUMBER_OF_GUESSES};
+
+const SAVED_GAME_FILE_NAME: &str = "saved_game.json";
+
+// a game that was left before it was over, to be continued the next time the game is started
+#[derive(Clone, Debug)]
+#[derive(Serialize, Deserialize)]
+pub struct SavedGame {
+    pub answer: String,
+    pub answer_index: Option<usize>,
+    pub mode: GameMode,
+    pub max_guesses: usize,
+    pub hard_mode: bool,
+    pub daily_date: Option<NaiveDate>,
+    pub guesses: Vec<String>,
+    // the letters typed into the next row, but not entered yet
+    pub partial_row: String,
+    pub time_played_secs: f64,
+}
+
+impl SavedGame {
+    pub fn from_engine(engine: &GameEngine, partial_row: &[char]) -> SavedGame {
+        SavedGame {
+            answer: engine.answer.iter().collect(),
+            answer_index: engine.answer_index,
+            mode: engine.mode,
+            max_guesses: engine.max_guesses,
+            hard_mode: engine.hard_mode,
+            daily_date: engine.daily_date,
+            guesses: engine.guesses.iter().map(|guess| guess.iter().collect()).collect(),
+            partial_row: partial_row.iter().collect(),
+            time_played_secs: engine.get_time_played().as_secs_f64(),
+        }
+    }
+
+    pub fn get_options(&self) -> GameOptions {
+        GameOptions {
+            word_length: self.answer.chars().count(),
+            max_guesses: self.max_guesses,
+            hard_mode: self.hard_mode,
+            mode: self.mode,
+            ..GameOptions::default()
+        }
+    }
+
+    // the saved game can take the place of a new one with these options: a new random game is given up
+    // for the saved game, a seed or a code only for the same one
+    pub fn can_continue(&self, options: &GameOptions) -> bool {
+        let saved = self.get_options();
+        saved.word_length == options.word_length
+            && saved.max_guesses == options.max_guesses
+            && saved.hard_mode == options.hard_mode
+            && (options.mode == GameMode::Random || options.mode == saved.mode)
+    }
+
+    // the engine as it was when the game was saved
+    pub fn to_engine(&self) -> GameEngine {
+        let options = self.get_options();
+        let mut engine = GameEngine::with_answer(self.answer.chars().collect(), &options);
+        engine.answer_index = self.answer_index;
+        let guesses: Vec<Vec<char>> = self.guesses.iter().map(|guess| guess.chars().collect()).collect();
+        engine.replay_guesses(&guesses);
+        engine.daily_date = self.daily_date;
+
+        let time_played = Duration::from_secs_f64(self.time_played_secs.max(0.0));
+        engine.started_at = Instant::now().checked_sub(time_played).unwrap_or_else(Instant::now);
+        engine
+    }
+
+    pub fn save(&self, dir: &Path) -> io::Result<()> {
+        let contents = serde_json::to_string_pretty(self)?;
+        assets::write_atomically(&dir.join(SAVED_GAME_FILE_NAME), &contents)
+    }
+
+    // the saved game, if there is one that is not broken
+    pub fn load(dir: &Path) -> Option<SavedGame> {
+        let contents = fs::read_to_string(dir.join(SAVED_GAME_FILE_NAME)).ok()?;
+        serde_json::from_str::<SavedGame>(&contents).ok()
+            .filter(|saved| {
+                (MIN_WORD_LENGTH..=MAX_WORD_LENGTH).contains(&saved.answer.len())
+                    && saved.answer.chars().all(|ch| ch.is_ascii_alphabetic())
+                    && (MIN_NUMBER_OF_GUESSES..=MAX_NUMBER_OF_GUESSES).contains(&saved.max_guesses)
+                    && saved.guesses.len() <= saved.max_guesses
+            })
+    }
+
+    // once the game is over or given up there is nothing to continue
+    pub fn remove(dir: &Path) {
+        let _ = fs::remove_file(dir.join(SAVED_GAME_FILE_NAME));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn load_from(dir_name: &str, contents: &str) -> Option<SavedGame> {
+        let dir = std::env::temp_dir().join(dir_name);
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join(SAVED_GAME_FILE_NAME), contents).unwrap();
+        let saved = SavedGame::load(&dir);
+        let _ = fs::remove_dir_all(&dir);
+        saved
+    }
+
+    fn saved_game_json(max_guesses: usize, guesses: &str) -> String {
+        format!(r#"{{"answer": "CRANE", "answer_index": null, "mode": "Random", "max_guesses": {}, "hard_mode": false,
+            "daily_date": null, "guesses": [{}], "partial_row": "", "time_played_secs": 1.0}}"#, max_guesses, guesses)
+    }
+
+    #[test]
+    fn saved_game_with_valid_rules_is_loaded() {
+        let saved = load_from("wordle_test_saved_game_valid", &saved_game_json(6, r#""SLATE""#)).unwrap();
+        assert_eq!(saved.to_engine().get_curr_row(), 1);
+    }
+
+    #[test]
+    fn saved_game_with_broken_rules_is_refused() {
+        assert!(load_from("wordle_test_saved_game_no_guesses", &saved_game_json(0, "")).is_none());
+        assert!(load_from("wordle_test_saved_game_many_guesses", &saved_game_json(1000, "")).is_none());
+        let too_many = [r#""SLATE""#; 5].join(", ");
+        assert!(load_from("wordle_test_saved_game_full", &saved_game_json(4, &too_many)).is_none());
+    }
+}
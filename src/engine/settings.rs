@@ -0,0 +1,83 @@
+use std::{fs, io, path::Path};
+
+use serde::{Deserialize, Serialize};
+
+use super::{assets, GameOptions};
+use super::{MIN_WORD_LENGTH, MAX_WORD_LENGTH, MIN_NUMBER_OF_GUESSES, MAX_NUMBER_OF_GUESSES};
+
+const SETTINGS_FILE_NAME: &str = "settings.json";
+
+// the preferences changed in the settings of the window, which every next game is started with;
+// the command line arguments still win over them
+#[derive(Clone, Debug)]
+#[derive(PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    pub word_length: usize,
+    pub max_guesses: usize,
+    pub hard_mode: bool,
+    pub theme: String,
+    pub animations: bool,
+    pub sound: bool,
+}
+
+impl Settings {
+    pub fn from_options(options: &GameOptions) -> Settings {
+        Settings {
+            word_length: options.word_length,
+            max_guesses: options.max_guesses,
+            hard_mode: options.hard_mode,
+            theme: options.theme.clone(),
+            animations: options.animations,
+            sound: options.sound,
+        }
+    }
+
+    // the options of a new game before the command line arguments are read
+    pub fn get_options(&self) -> GameOptions {
+        GameOptions {
+            word_length: self.word_length,
+            max_guesses: self.max_guesses,
+            hard_mode: self.hard_mode,
+            theme: self.theme.clone(),
+            animations: self.animations,
+            sound: self.sound,
+            ..GameOptions::default()
+        }
+    }
+
+    pub fn save(&self, dir: &Path) -> io::Result<()> {
+        let contents = serde_json::to_string_pretty(self)?;
+        assets::write_atomically(&dir.join(SETTINGS_FILE_NAME), &contents)
+    }
+
+    // the saved settings, the default ones if there are none; a value out of its range is replaced by the default one
+    pub fn load(dir: &Path) -> Settings {
+        let contents = match fs::read_to_string(dir.join(SETTINGS_FILE_NAME)) {
+            Ok(contents) => contents,
+            Err(_) => return Settings::default(),
+        };
+        let mut settings = match serde_json::from_str::<Settings>(&contents) {
+            Ok(settings) => settings,
+            Err(err) => {
+                eprintln!("Could not read the settings: {}", err);
+                return Settings::default();
+            },
+        };
+
+        let default = Settings::default();
+        if !(MIN_WORD_LENGTH..=MAX_WORD_LENGTH).contains(&settings.word_length) {
+            settings.word_length = default.word_length;
+        }
+        if !(MIN_NUMBER_OF_GUESSES..=MAX_NUMBER_OF_GUESSES).contains(&settings.max_guesses) {
+            settings.max_guesses = default.max_guesses;
+        }
+        settings
+    }
+}
+
+impl Default for Settings {
+    fn default() -> Settings {
+        Settings::from_options(&GameOptions::default())
+    }
+}
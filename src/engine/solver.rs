@@ -0,0 +1,213 @@
+use std::{collections::HashMap, sync::Mutex};
+
+use super::{score, Dictionary, Feedback, GameEngine};
+
+// how the guesses are ranked
+#[derive(Copy, Clone, Debug)]
+#[derive(PartialEq, Eq, Hash)]
+pub enum Strategy {
+    // the most information expected from the colors of the guess
+    Entropy,
+    // the fewest answers left in the worst case
+    Minimax,
+}
+
+// keeps the answers that agree with every guess so far and picks the next guess from them
+pub struct Solver {
+    word_length: usize,
+    candidates: Vec<Vec<char>>,
+    strategy: Strategy,
+    hard_mode: bool,
+    // nothing is known yet, so the best guess is always the same
+    is_at_start: bool,
+}
+
+// the first guess takes the longest to find, so it is found once for every word length, strategy and mode
+type OpeningGuesses = HashMap<(usize, Strategy, bool), Option<Vec<char>>>;
+static OPENING_GUESSES: Mutex<Option<OpeningGuesses>> = Mutex::new(None);
+
+// how much one guess of a game helped
+#[derive(Clone, Debug)]
+pub struct GuessAnalysis {
+    pub guess: Vec<char>,
+    pub candidates_before: usize,
+    pub candidates_after: usize,
+    // the information the colors gave: how many times fewer the candidates became, in powers of two
+    pub bits: f64,
+    // what the solver would have guessed instead
+    pub best_guess: Option<Vec<char>>,
+}
+
+impl Solver {
+    pub fn new(word_length: usize, strategy: Strategy) -> Solver {
+        Solver {
+            word_length,
+            candidates: Dictionary::get(word_length).get_answers().to_vec(),
+            strategy,
+            hard_mode: false,
+            is_at_start: true,
+        }
+    }
+
+    // the solver for the next guess of the game
+    pub fn from_engine(engine: &GameEngine, strategy: Strategy) -> Solver {
+        let mut solver = Solver::new(engine.get_word_length(), strategy);
+        solver.hard_mode = engine.is_hard_mode();
+        for (guess, feedback) in engine.get_guesses().iter().zip(engine.get_feedback()) {
+            solver.apply(guess, *feedback);
+        }
+        solver
+    }
+
+    // only the answers that would have given the same colors to the guess are left
+    pub fn apply(&mut self, guess: &[char], feedback: Feedback) {
+        self.is_at_start = false;
+        self.candidates.retain(|candidate| score(guess, candidate) == feedback);
+    }
+
+    pub fn get_candidates(&self) -> &[Vec<char>] {
+        &self.candidates
+    }
+
+    pub fn get_number_of_candidates(&self) -> usize {
+        self.candidates.len()
+    }
+
+    // the best guess, none if no answer agrees with the guesses
+    pub fn suggest(&self) -> Option<Vec<char>> {
+        // with two answers left guessing one of them is never worse than anything else
+        if self.candidates.len() <= 2 {
+            return self.candidates.first().cloned();
+        }
+        if self.is_at_start {
+            let key = (self.word_length, self.strategy, self.hard_mode);
+            if let Some(opening_guess) = OPENING_GUESSES.lock().unwrap().get_or_insert_with(HashMap::new).get(&key) {
+                return opening_guess.clone();
+            }
+            let opening_guess = self.rank_guesses(1).into_iter().next().map(|(guess, _)| guess);
+            OPENING_GUESSES.lock().unwrap().get_or_insert_with(HashMap::new).insert(key, opening_guess.clone());
+            return opening_guess;
+        }
+        self.rank_guesses(1).into_iter().next().map(|(guess, _)| guess)
+    }
+
+    // the best guesses with their values, the best first; a higher value is always better
+    pub fn rank_guesses(&self, limit: usize) -> Vec<(Vec<char>, f64)> {
+        // in hard mode only the candidates keep all the hints, otherwise any allowed word may split them better
+        let guesses: &[Vec<char>] = if self.hard_mode {
+            &self.candidates
+        }
+        else {
+            Dictionary::get(self.word_length).get_allowed_guesses()
+        };
+
+        let mut pattern_counts: Vec<u32> = vec![0; Feedback::get_number_of_patterns(self.word_length)];
+        let mut ranked: Vec<(Vec<char>, f64)> = guesses.iter()
+            .map(|guess| (guess.clone(), self.get_value_of_guess(guess, &mut pattern_counts)))
+            .collect();
+
+        // the order of the guesses decides between equal values, so the result does not change from run to run
+        ranked.sort_by(|a, b| b.1.total_cmp(&a.1));
+        ranked.truncate(limit);
+        ranked
+    }
+
+    fn get_value_of_guess(&self, guess: &[char], pattern_counts: &mut [u32]) -> f64 {
+        pattern_counts.iter_mut().for_each(|count| *count = 0);
+        for candidate in &self.candidates {
+            pattern_counts[score(guess, candidate).get_pattern() as usize] += 1;
+        }
+
+        // a guess that may be the answer itself wins a tie
+        let is_candidate = self.candidates.iter().any(|candidate| candidate.as_slice() == guess);
+        let bonus_of_candidate = if is_candidate { 1.0 / (self.candidates.len() as f64 + 1.0) } else { 0.0 };
+
+        match self.strategy {
+            Strategy::Entropy => {
+                let total = self.candidates.len() as f64;
+                let entropy: f64 = pattern_counts.iter()
+                    .filter(|count| **count != 0)
+                    .map(|count| {
+                        let probability = *count as f64 / total;
+                        -probability * probability.log2()
+                    })
+                    .sum();
+                entropy + bonus_of_candidate * 0.01
+            },
+            Strategy::Minimax => {
+                let worst_case = pattern_counts.iter().copied().max().unwrap_or(0);
+                -(worst_case as f64) + bonus_of_candidate
+            },
+        }
+    }
+}
+
+// every guess of the game next to what the solver would have done at the same point
+pub fn analyze_game(engine: &GameEngine, strategy: Strategy) -> Vec<GuessAnalysis> {
+    let mut solver = Solver::new(engine.get_word_length(), strategy);
+    solver.hard_mode = engine.is_hard_mode();
+
+    let mut analysis: Vec<GuessAnalysis> = Vec::with_capacity(engine.get_curr_row());
+    for (guess, feedback) in engine.get_guesses().iter().zip(engine.get_feedback()) {
+        let candidates_before = solver.get_number_of_candidates();
+        let best_guess = solver.suggest();
+        solver.apply(guess, *feedback);
+        let candidates_after = solver.get_number_of_candidates();
+
+        analysis.push(GuessAnalysis {
+            guess: guess.clone(),
+            candidates_before,
+            candidates_after,
+            // an answer from outside of the list leaves no candidates, which tells nothing
+            bits: (candidates_before.max(1) as f64 / candidates_after.max(1) as f64).log2(),
+            best_guess,
+        });
+    }
+    analysis
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chars(word: &str) -> Vec<char> {
+        word.chars().collect()
+    }
+
+    #[test]
+    fn apply_keeps_the_answers_with_the_same_colors() {
+        let answers = Dictionary::get(5).get_answers();
+        let guess = chars("CRANE");
+        let feedback = score(&guess, &answers[0]);
+
+        let mut solver = Solver::new(5, Strategy::Entropy);
+        solver.apply(&guess, feedback);
+
+        let expected: Vec<Vec<char>> = answers.iter()
+            .filter(|answer| score(&guess, answer) == feedback)
+            .cloned()
+            .collect();
+        assert_eq!(solver.get_candidates(), expected.as_slice());
+        assert!(solver.get_candidates().contains(&answers[0]));
+    }
+
+    #[test]
+    fn one_of_the_last_two_candidates_is_suggested() {
+        let answer = Dictionary::get(5).get_answers()[0].clone();
+        let mut solver = Solver::new(5, Strategy::Minimax);
+        solver.apply(&answer, score(&answer, &answer));
+        assert_eq!(solver.get_number_of_candidates(), 1);
+        assert_eq!(solver.suggest(), Some(answer));
+    }
+
+    #[test]
+    fn nothing_is_suggested_when_no_candidate_fits() {
+        let answers = Dictionary::get(5).get_answers();
+        let mut solver = Solver::new(5, Strategy::Entropy);
+        // two different words cannot both be the answer
+        solver.apply(&answers[0], score(&answers[0], &answers[0]));
+        solver.apply(&answers[1], score(&answers[1], &answers[1]));
+        assert_eq!(solver.get_number_of_candidates(), 0);
+        assert_eq!(solver.suggest(), None);
+    }
+}
@@ -0,0 +1,309 @@
+use std::{fs, io, path::Path};
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use super::{assets, history::{self, GameRecord}, DEFAULT_WORD_LENGTH};
+
+// raised whenever the fields change; the fields missing in an older file are filled in when it is loaded
+// 1: the games by attempt, the games lost and the hard mode wins
+// 2: the streaks
+pub const STATS_VERSION: u32 = 2;
+
+// the numbers kept between the games of one word length
+#[derive(Clone, Debug, Default)]
+#[derive(Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameStats {
+    pub version: u32,
+    // the number of games won by the first, second, third... guess
+    pub games_won_by_attempt: Vec<u32>,
+    pub games_lost: u32,
+    pub hard_mode_wins: u32,
+    // the games won in a row, whatever their mode
+    pub current_streak: u32,
+    pub max_streak: u32,
+    // the daily games won on consecutive days
+    pub daily_streak: u32,
+    pub max_daily_streak: u32,
+    pub last_daily_win: Option<NaiveDate>,
+}
+
+impl GameStats {
+    pub fn new() -> GameStats {
+        GameStats {
+            version: STATS_VERSION,
+            ..GameStats::default()
+        }
+    }
+
+    // every word length keeps its own statistics
+    fn get_file_name(word_length: usize) -> String {
+        if word_length == DEFAULT_WORD_LENGTH {
+            String::from("stats.json")
+        }
+        else {
+            format!("stats_{}.json", word_length)
+        }
+    }
+
+    // the numbers separated by spaces from before the json files
+    fn get_legacy_file_name(word_length: usize) -> String {
+        if word_length == DEFAULT_WORD_LENGTH {
+            String::from("stats")
+        }
+        else {
+            format!("stats_{}", word_length)
+        }
+    }
+
+    // the statistics from the directory, taken over from the legacy file when there is no json file yet
+    // and made again from the history of the games when there is neither or it is broken
+    pub fn load(dir: &Path, word_length: usize) -> GameStats {
+        let file_path = dir.join(GameStats::get_file_name(word_length));
+
+        if let Ok(contents) = fs::read_to_string(&file_path) {
+            return match serde_json::from_str::<GameStats>(&contents) {
+                Ok(stats) if stats.version < 2 => stats.with_streaks_from_history(dir, word_length),
+                Ok(stats) => stats,
+                Err(err) => {
+                    // the broken file is kept aside instead of being overwritten by the next game
+                    eprintln!("The statistics in {} are corrupt ({}), rebuilding them from the history", file_path.display(), err);
+                    let _ = fs::rename(&file_path, file_path.with_extension("corrupt"));
+                    GameStats::from_history(&history::load_history(dir), word_length)
+                },
+            };
+        }
+
+        // the legacy statistics used to be kept next to the sources before they were moved to the data directory
+        let legacy_file_name = GameStats::get_legacy_file_name(word_length);
+        let legacy_contents = fs::read_to_string(dir.join(&legacy_file_name))
+            .or_else(|_err| fs::read_to_string(Path::new("src/wordle").join(&legacy_file_name)));
+        match legacy_contents {
+            Ok(contents) => GameStats::from_legacy(&contents)
+                .map(|stats| stats.with_streaks_from_history(dir, word_length))
+                .unwrap_or_else(|| {
+                    eprintln!("The legacy statistics in {} are corrupt, rebuilding them from the history", legacy_file_name);
+                    GameStats::from_history(&history::load_history(dir), word_length)
+                }),
+            Err(_err) => GameStats::from_history(&history::load_history(dir), word_length),
+        }
+    }
+
+    // the same numbers counted from the recorded games of the word length;
+    // a word length that was never played before gets empty statistics; a record that cannot be a real game
+    // (won without a guess or with more guesses than allowed) is skipped
+    pub fn from_history(records: &[GameRecord], word_length: usize) -> GameStats {
+        let mut stats = GameStats::new();
+        let records = records.iter()
+            .filter(|record| record.word_length == word_length)
+            .filter(|record| !(record.won && record.guesses.is_empty()) && record.guesses.len() <= record.max_guesses);
+        for record in records {
+            stats.record_game(record.won.then_some(record.guesses.len()), record.hard_mode, record.daily_date);
+        }
+        stats
+    }
+
+    // the files from before the streaks get them from the history, as far as it goes back
+    fn with_streaks_from_history(self, dir: &Path, word_length: usize) -> GameStats {
+        let from_history = GameStats::from_history(&history::load_history(dir), word_length);
+        GameStats {
+            version: STATS_VERSION,
+            current_streak: from_history.current_streak,
+            max_streak: from_history.max_streak,
+            daily_streak: from_history.daily_streak,
+            max_daily_streak: from_history.max_daily_streak,
+            last_daily_win: from_history.last_daily_win,
+            ..self
+        }
+    }
+
+    // the first line has the games won by every attempt followed by the games lost,
+    // the second one the games won in hard mode (missing in the oldest files)
+    pub fn from_legacy(contents: &str) -> Option<GameStats> {
+        let mut lines = contents.lines();
+
+        let mut games_won_by_attempt: Vec<u32> = lines.next()?
+            .split_whitespace()
+            .map(|s| s.parse::<u32>().ok())
+            .collect::<Option<Vec<u32>>>()?;
+        let games_lost = games_won_by_attempt.pop()?;
+
+        let hard_mode_wins = match lines.next() {
+            Some(line) => line.trim().parse::<u32>().ok()?,
+            None => 0,
+        };
+
+        Some(GameStats {
+            version: 1,
+            games_won_by_attempt,
+            games_lost,
+            hard_mode_wins,
+            ..GameStats::default()
+        })
+    }
+
+    pub fn save(&self, dir: &Path, word_length: usize) -> io::Result<()> {
+        let contents = serde_json::to_string_pretty(self)?;
+        assets::write_atomically(&dir.join(GameStats::get_file_name(word_length)), &contents)
+    }
+
+    // the attempt the game was won by, or none if it was lost, and the day of the puzzle if it was a daily game;
+    // a game won by no attempt at all is not a game and is not counted
+    pub fn record_game(&mut self, attempt: Option<usize>, hard_mode: bool, daily_date: Option<NaiveDate>) {
+        if attempt == Some(0) {
+            return;
+        }
+        self.version = STATS_VERSION;
+        if let Some(attempt) = attempt {
+            if hard_mode {
+                self.hard_mode_wins += 1;
+            }
+            if self.games_won_by_attempt.len() < attempt {
+                self.games_won_by_attempt.resize(attempt, 0);
+            }
+            self.games_won_by_attempt[attempt - 1] += 1;
+
+            self.current_streak += 1;
+            self.max_streak = self.max_streak.max(self.current_streak);
+        }
+        else {
+            self.games_lost += 1;
+            self.current_streak = 0;
+        }
+
+        if let Some(date) = daily_date {
+            if attempt.is_some() {
+                let won_the_day_before = self.last_daily_win.and_then(|last| last.succ_opt()) == Some(date);
+                self.daily_streak = if won_the_day_before { self.daily_streak + 1 } else { 1 };
+                self.max_daily_streak = self.max_daily_streak.max(self.daily_streak);
+                self.last_daily_win = Some(date);
+            }
+            else {
+                self.daily_streak = 0;
+            }
+        }
+    }
+
+    // the daily streak is broken as soon as a day passes without a win
+    pub fn get_daily_streak(&self, today: NaiveDate) -> u32 {
+        match self.last_daily_win {
+            Some(last) if last == today || last.succ_opt() == Some(today) => self.daily_streak,
+            _ => 0,
+        }
+    }
+
+    pub fn get_games_played(&self) -> u32 {
+        self.games_won_by_attempt.iter().sum::<u32>() + self.games_lost
+    }
+
+    pub fn get_win_rate(&self) -> f32 {
+        if self.get_games_played() == 0 {
+            return 0.0;
+        }
+        ((self.get_games_played() - self.games_lost) as f32 / (self.get_games_played()) as f32) * 100.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn legacy_baseline_file_is_read() {
+        let stats = GameStats::from_legacy(include_str!("../wordle/stats")).unwrap();
+        assert_eq!(stats.games_won_by_attempt, vec![0, 0, 6, 29, 50, 50]);
+        assert_eq!(stats.games_lost, 19);
+        assert_eq!(stats.hard_mode_wins, 0);
+        assert_eq!(stats.get_games_played(), 154);
+    }
+
+    #[test]
+    fn legacy_file_with_hard_mode_wins_is_read() {
+        let stats = GameStats::from_legacy("1 2 3 4 5 6 7\r\n8\r\n").unwrap();
+        assert_eq!(stats.games_won_by_attempt, vec![1, 2, 3, 4, 5, 6]);
+        assert_eq!(stats.games_lost, 7);
+        assert_eq!(stats.hard_mode_wins, 8);
+        assert_eq!(stats.version, 1);
+    }
+
+    fn record(won: bool, number_of_guesses: usize, max_guesses: usize) -> GameRecord {
+        GameRecord {
+            timestamp: String::new(),
+            word_length: DEFAULT_WORD_LENGTH,
+            max_guesses,
+            answer: String::from("CRANE"),
+            guesses: vec![String::from("CRANE"); number_of_guesses],
+            feedback: Vec::new(),
+            mode: crate::engine::GameMode::Random,
+            daily_date: None,
+            hard_mode: false,
+            game_code: None,
+            duration_secs: 0.0,
+            won,
+        }
+    }
+
+    #[test]
+    fn impossible_history_records_are_skipped() {
+        let records = [record(true, 0, 6), record(true, 9, 6), record(false, 7, 6), record(true, 3, 6), record(false, 6, 6)];
+        let stats = GameStats::from_history(&records, DEFAULT_WORD_LENGTH);
+        assert_eq!(stats.games_won_by_attempt, vec![0, 0, 1]);
+        assert_eq!(stats.games_lost, 1);
+    }
+
+    #[test]
+    fn game_won_by_no_attempt_is_not_counted() {
+        let mut stats = GameStats::new();
+        stats.record_game(Some(0), false, None);
+        assert_eq!(stats.get_games_played(), 0);
+    }
+
+    fn day(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 3, day).unwrap()
+    }
+
+    #[test]
+    fn streak_is_reset_by_a_loss() {
+        let mut stats = GameStats::new();
+        stats.record_game(Some(3), false, None);
+        stats.record_game(Some(4), false, None);
+        stats.record_game(None, false, None);
+        assert_eq!((stats.current_streak, stats.max_streak), (0, 2));
+
+        stats.record_game(Some(2), false, None);
+        assert_eq!((stats.current_streak, stats.max_streak), (1, 2));
+    }
+
+    #[test]
+    fn daily_streak_counts_only_consecutive_days() {
+        let mut stats = GameStats::new();
+        stats.record_game(Some(3), false, Some(day(1)));
+        stats.record_game(Some(4), false, Some(day(2)));
+        assert_eq!((stats.daily_streak, stats.max_daily_streak), (2, 2));
+
+        // the 3rd was skipped
+        stats.record_game(Some(2), false, Some(day(4)));
+        assert_eq!((stats.daily_streak, stats.max_daily_streak), (1, 2));
+
+        stats.record_game(None, false, Some(day(5)));
+        assert_eq!((stats.daily_streak, stats.max_daily_streak), (0, 2));
+    }
+
+    #[test]
+    fn daily_streak_lasts_until_a_day_is_missed() {
+        let mut stats = GameStats::new();
+        stats.record_game(Some(3), false, Some(day(1)));
+        stats.record_game(Some(3), false, Some(day(2)));
+        assert_eq!(stats.get_daily_streak(day(2)), 2);
+        assert_eq!(stats.get_daily_streak(day(3)), 2);
+        assert_eq!(stats.get_daily_streak(day(4)), 0);
+    }
+
+    #[test]
+    fn corrupt_legacy_files_are_refused() {
+        assert!(GameStats::from_legacy("").is_none());
+        assert!(GameStats::from_legacy("1 2 three 4 5 6 7").is_none());
+        assert!(GameStats::from_legacy("1 2 3 4 5 6 7\nmany").is_none());
+    }
+}
@@ -0,0 +1,5 @@
+pub mod engine;
+pub mod wordle;
+
+pub const WINDOW_WIDTH: f32 = 1080.0;
+pub const WINDOW_HEIGHT: f32 = 800.0;
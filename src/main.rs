@@ -1,27 +1,67 @@
 use ggez::{
+    conf::FullscreenType,
     event::{self, MouseButton},
-    input::keyboard::KeyInput,
+    input::keyboard::{KeyCode, KeyInput},
     glam::*,
-    graphics::{self, Color},
+    graphics::{self, Rect},
     Context, GameResult
 };
 
-mod wordle;
-use crate::wordle::Wordle;
-
-const WINDOW_WIDTH: f32 = 1080.0;
-const WINDOW_HEIGHT: f32 = 800.0;
-
-const BACKGROUND_COLOR: Color = Color::new(0.08, 0.08, 0.08, 1.0);
+use game::{engine::{assets, settings::Settings, GameOptions}, wordle::{theme::Theme, Wordle}, WINDOW_WIDTH, WINDOW_HEIGHT};
 
 struct MainState {
     wordle: Wordle,
+    // the game is laid out for a window of WINDOW_WIDTH x WINDOW_HEIGHT and scaled to the real size of the window,
+    // keeping its proportions and centered, with the rest of the window left empty
+    scale: f32,
+    offset: Vec2,
+    is_fullscreen: bool,
 }
 
 impl MainState {
-    fn new(ctx: &mut Context) -> GameResult<MainState> {
-        let wordle = Wordle::new(ctx);
-        Ok (MainState {wordle})
+    fn new(ctx: &mut Context, options: GameOptions) -> GameResult<MainState> {
+        let wordle = Wordle::resume(ctx, options);
+        let mut state = MainState {
+            wordle,
+            scale: 1.0,
+            offset: Vec2::ZERO,
+            is_fullscreen: false,
+        };
+        let (width, height) = ctx.gfx.drawable_size();
+        state.update_layout(width, height);
+        Ok (state)
+    }
+
+    fn update_layout(&mut self, width: f32, height: f32) {
+        self.scale = (width / WINDOW_WIDTH).min(height / WINDOW_HEIGHT);
+        self.offset = Vec2::new(
+            (width - WINDOW_WIDTH * self.scale) / 2.0,
+            (height - WINDOW_HEIGHT * self.scale) / 2.0,
+        );
+    }
+
+    // the part of the layout that the whole window shows
+    fn get_screen_coordinates(&self, ctx: &Context) -> Rect {
+        let (width, height) = ctx.gfx.drawable_size();
+        Rect::new(
+            -self.offset.x / self.scale,
+            -self.offset.y / self.scale,
+            width / self.scale,
+            height / self.scale,
+        )
+    }
+
+    // turns a point of the window into a point of the layout, which is what the buttons are hit tested against
+    fn to_layout_coordinates(&self, x: f32, y: f32) -> (f32, f32) {
+        ((x - self.offset.x) / self.scale, (y - self.offset.y) / self.scale)
+    }
+
+    fn toggle_fullscreen(&mut self, ctx: &mut Context) {
+        let fullscreen_type = if self.is_fullscreen { FullscreenType::Windowed } else { FullscreenType::Desktop };
+        match ctx.gfx.set_fullscreen(fullscreen_type) {
+            Ok(_) => self.is_fullscreen = !self.is_fullscreen,
+            Err(err) => eprintln!("Could not change the fullscreen mode: {}", err),
+        }
     }
 }
 
@@ -35,7 +75,8 @@ impl event::EventHandler<ggez::GameError> for MainState {
 
     fn draw(&mut self, ctx: &mut Context) -> GameResult {
         let mut canvas =
-            graphics::Canvas::from_frame(ctx, BACKGROUND_COLOR);
+            graphics::Canvas::from_frame(ctx, Theme::get().background);
+        canvas.set_screen_coordinates(self.get_screen_coordinates(ctx));
 
         let _ = Wordle::draw_wordle(&mut self.wordle, &mut canvas, ctx);
 
@@ -45,24 +86,44 @@ impl event::EventHandler<ggez::GameError> for MainState {
     }
 
     fn mouse_button_down_event(&mut self, _ctx: &mut Context, button: MouseButton, x: f32, y: f32) -> GameResult {
+        let (x, y) = self.to_layout_coordinates(x, y);
         self.wordle.detect_click (_ctx, button, x, y);
         Ok(())
     }
 
     fn key_down_event(&mut self, ctx: &mut Context, input: KeyInput, _repeat: bool) -> GameResult {
+        if input.keycode == Some(KeyCode::F11) {
+            self.toggle_fullscreen(ctx);
+            return Ok(());
+        }
         self.wordle.detect_typing(ctx, input);
         Ok(())
     }
+
+    fn resize_event(&mut self, _ctx: &mut Context, width: f32, height: f32) -> GameResult {
+        self.update_layout(width, height);
+        Ok(())
+    }
+
+    fn quit_event(&mut self, ctx: &mut Context) -> GameResult<bool> {
+        self.wordle.save_game(ctx);
+        Ok(false)
+    }
 }
 
 
 pub fn main() -> GameResult {
-    let cb = ggez::ContextBuilder::new("wordle", "az")
+    // the saved settings, changed by the arguments of this start
+    let settings = Settings::load(&assets::get_data_dir().unwrap_or_default());
+    let options = settings.get_options().with_args(std::env::args());
+    let cb = ggez::ContextBuilder::new(assets::GAME_ID, assets::AUTHOR)
         .window_setup(ggez::conf::WindowSetup::default()
         .title("Wordle"))
         .window_mode(ggez::conf::WindowMode::default()
-        .dimensions(WINDOW_WIDTH, WINDOW_HEIGHT));
+        .dimensions(WINDOW_WIDTH, WINDOW_HEIGHT)
+        .resizable(true)
+        .min_dimensions(WINDOW_WIDTH / 2.0, WINDOW_HEIGHT / 2.0));
     let (mut ctx, event_loop) = cb.build()?;
-    let state = MainState::new(&mut ctx)?;
+    let state = MainState::new(&mut ctx, options)?;
     event::run(ctx, event_loop, state);
 }
\ No newline at end of file
@@ -1,45 +1,52 @@
-
-
 use std::time::Duration;
 
 use ggez::{
     event::MouseButton,
     input::keyboard::{KeyCode, KeyInput},
     glam::*,
-    graphics::{self, Color},
+    graphics,
     Context, GameResult,
 };
 
-mod utility;
-use self::utility::{UpperLetter, LowerLetter, lua_wrapper, AnimatedBox, swipe_animation, roll_animation, no_animation, AnimatedArguments, Statistics};
-
-const WORDLE_ROWS: usize = 6;
-const WORDLE_COLS: usize = 5;
-
-const WORDLE_LETTERS_COUNT: usize = 26;
-
+use crate::engine::{assets, daily, saved_game::SavedGame, settings::Settings, solver::{Solver, Strategy}, GameEngine, GameMode, GameOptions, GameState, State, WORDLE_LETTERS_COUNT};
+use crate::engine::{MIN_WORD_LENGTH, MAX_WORD_LENGTH, MIN_NUMBER_OF_GUESSES, MAX_NUMBER_OF_GUESSES};
 
-#[derive(PartialEq)]
-enum GameState {
-    NotOver,
-    Won,
-    Lost,
-}
+mod sound;
+use self::sound::Sounds;
+pub mod theme;
+use self::theme::Theme;
+mod utility;
+use self::utility::{Analysis, UpperLetter, LowerLetter, AnimatedBox, AnimationFunction, swipe_animation, roll_animation, no_animation, AnimatedArguments, Setting, SettingsMenu, Statistics};
 
 pub struct Wordle {
-    game_board: [[UpperLetter; WORDLE_COLS]; WORDLE_ROWS],
+    game_board: Vec<Vec<UpperLetter>>,
     used_letters: [LowerLetter; WORDLE_LETTERS_COUNT],
     enter_button: LowerLetter,
     delete_button: LowerLetter,
-    answer: [char; WORDLE_COLS],
+    hint_button: LowerLetter,
+    engine: GameEngine,
+    options: GameOptions,
+    // the settings as they are saved, without the arguments of this start or the rules of a resumed game
+    settings: Settings,
     curr_letter: (usize, usize),
-    game_state: GameState,
+    // the row whose tiles are being turned over, nothing can be typed until they are
+    row_being_revealed: Option<usize>,
     you_won_box: AnimatedBox,
     shown_answer_box: AnimatedBox,
     invalid_word: AnimatedBox,
     replay_button: AnimatedBox,
+    game_code_box: AnimatedBox,
+    share_button: AnimatedBox,
+    // kept for as long as the game runs, the copied text is lost with it on some systems
+    clipboard: Option<arboard::Clipboard>,
+    remaining_box: AnimatedBox,
+    analysis_button: AnimatedBox,
+    analysis: Analysis,
     stats: Statistics,
     see_stats_button: AnimatedBox,
+    settings_button: AnimatedBox,
+    settings_menu: SettingsMenu,
+    sounds: Sounds,
 }
 
 impl Wordle {
@@ -47,6 +54,9 @@ impl Wordle {
     const SEPARATION_BETWEEN_LOWEER_LETTERS: f32 = 6.2;
     const DIST_TO_UPPER_BLOCK: f32 = 55.0;
     const DIST_TO_LOWER_BLOCK: f32 = 575.0;
+    const MAX_WIDTH_OF_UPPER_BLOCK: f32 = 420.0;
+    const MAX_HEIGHT_OF_UPPER_BLOCK: f32 = 380.0;
+    const SHARE_FILE_NAME: &'static str = "share.txt";
 
     // gets the y coord of the top of the upper block
     fn dist_to_top_of_upper_block() -> f32 {
@@ -54,18 +64,18 @@ impl Wordle {
     }
 
     // gets the y coord of the bottom of the upper block
-    fn dist_to_bottom_of_upper_block() -> f32 {
-        Self::dist_to_top_of_upper_block() + Self::get_height_of_upper_block()
+    fn dist_to_bottom_of_upper_block(options: &GameOptions) -> f32 {
+        Self::dist_to_top_of_upper_block() + Self::get_height_of_upper_block(options)
     }
 
     // gets the x coord of the left of the upper block
-    fn dist_to_left_of_upper_block() -> f32 {
-        (super::WINDOW_WIDTH - Self::get_width_of_upper_block()) / 2.0
+    fn dist_to_left_of_upper_block(options: &GameOptions) -> f32 {
+        (super::WINDOW_WIDTH - Self::get_width_of_upper_block(options)) / 2.0
     }
 
     // gets the x coord of the right of the upper block
-    fn dist_to_right_of_upper_block() -> f32 {
-        Self::dist_to_left_of_upper_block() + Self::get_width_of_upper_block()
+    fn dist_to_right_of_upper_block(options: &GameOptions) -> f32 {
+        Self::dist_to_left_of_upper_block(options) + Self::get_width_of_upper_block(options)
     }
 
     // gets the y coord of the top of the lower block
@@ -78,71 +88,84 @@ impl Wordle {
         (super::WINDOW_WIDTH - (10.0*LowerLetter::RECT_WIDTH + 9.0*Wordle::SEPARATION_BETWEEN_LOWEER_LETTERS)) / 2.0
     }
 
-    fn get_width_of_upper_block() -> f32 {
-        UpperLetter::RECT_WIDTH*WORDLE_COLS as f32 + Wordle::SEPARATION_BETWEEN_UPPER_LETTERS*(WORDLE_COLS - 1) as f32
+    // the tiles of the board get smaller for the longer words and the bigger number of guesses so that the board fits
+    fn get_size_of_upper_letter(options: &GameOptions) -> f32 {
+        let cols = options.word_length as f32;
+        let rows = options.max_guesses as f32;
+        let fitting_width = (Wordle::MAX_WIDTH_OF_UPPER_BLOCK - Wordle::SEPARATION_BETWEEN_UPPER_LETTERS*(cols - 1.0)) / cols;
+        let fitting_height = (Wordle::MAX_HEIGHT_OF_UPPER_BLOCK - Wordle::SEPARATION_BETWEEN_UPPER_LETTERS*(rows - 1.0)) / rows;
+        fitting_width.min(fitting_height).min(UpperLetter::RECT_WIDTH)
+    }
+
+    fn get_width_of_upper_block(options: &GameOptions) -> f32 {
+        Self::get_size_of_upper_letter(options)*options.word_length as f32 + Wordle::SEPARATION_BETWEEN_UPPER_LETTERS*(options.word_length - 1) as f32
+    }
+
+    fn get_height_of_upper_block(options: &GameOptions) -> f32 {
+        Self::get_size_of_upper_letter(options)*options.max_guesses as f32 + Wordle::SEPARATION_BETWEEN_UPPER_LETTERS*(options.max_guesses - 1) as f32
     }
 
-    fn get_height_of_upper_block() -> f32 {
-        UpperLetter::RECT_HEIGHT*WORDLE_ROWS as f32 + Wordle::SEPARATION_BETWEEN_UPPER_LETTERS*(WORDLE_ROWS - 1) as f32
+    pub fn new(ctx: &mut Context, options: GameOptions) -> Self {
+        let engine: GameEngine = GameEngine::new(&options);
+        // today's daily game keeps the rules it was started with, the board is made for them
+        let options = GameOptions {
+            max_guesses: engine.get_max_guesses(),
+            hard_mode: engine.is_hard_mode(),
+            ..options
+        };
+        Wordle::with_engine(ctx, options, engine)
     }
 
-    pub fn new(ctx: &mut Context) -> Self {
-        let mut offsets: [(f32, f32); WORDLE_COLS*WORDLE_ROWS] = [(0.0, 0.0); WORDLE_COLS*WORDLE_ROWS];
-        let mut offset_x: f32 = Self::dist_to_left_of_upper_block();
+    // continues the game left unfinished the last time if it fits the options, otherwise starts a new one
+    pub fn resume(ctx: &mut Context, options: GameOptions) -> Self {
+        let saved_game = SavedGame::load(ctx.fs.user_data_dir())
+            .filter(|saved_game| saved_game.can_continue(&options));
+
+        match saved_game {
+            Some(saved_game) => {
+                // the options that are not about the rules stay as they were given
+                let saved_rules = saved_game.get_options();
+                let saved_options = GameOptions {
+                    word_length: saved_rules.word_length,
+                    max_guesses: saved_rules.max_guesses,
+                    hard_mode: saved_rules.hard_mode,
+                    mode: saved_rules.mode,
+                    ..options
+                };
+                let mut wordle = Wordle::with_engine(ctx, saved_options, saved_game.to_engine());
+                for ch in saved_game.partial_row.chars() {
+                    letter_clicked(ctx, &mut wordle, Some(ch));
+                }
+                wordle
+            },
+            None => Wordle::new(ctx, options),
+        }
+    }
+
+    fn with_engine(ctx: &mut Context, options: GameOptions, engine: GameEngine) -> Self {
+        // the theme is taken before anything is made, since the letters and the boxes keep the colors they are made with
+        let theme = Theme::load(&options.theme, ctx.fs.user_data_dir());
+        Theme::set(theme);
+
+        // without the animations the boxes stay where they are put
+        let roll: AnimationFunction = if options.animations { roll_animation } else { no_animation };
+        let swipe_speed: f32 = if options.animations { 630.0 } else { 0.0 };
+
+        let word_length = options.word_length;
+        let size_of_upper_letter = Self::get_size_of_upper_letter(&options);
+
+        let mut letters: Vec<Vec<UpperLetter>> = Vec::with_capacity(options.max_guesses);
         let mut offset_y: f32 = Self::dist_to_top_of_upper_block();
-        for i in 0..WORDLE_ROWS*WORDLE_COLS {
-            if i % 5 == 0 && i != 0 {
-                offset_y += Wordle::SEPARATION_BETWEEN_UPPER_LETTERS + UpperLetter::RECT_HEIGHT;
-                offset_x = Self::dist_to_left_of_upper_block();
+        for _ in 0..options.max_guesses {
+            let mut row: Vec<UpperLetter> = Vec::with_capacity(word_length);
+            let mut offset_x: f32 = Self::dist_to_left_of_upper_block(&options);
+            for _ in 0..word_length {
+                row.push(UpperLetter::new(ctx, offset_x, offset_y, size_of_upper_letter));
+                offset_x += Wordle::SEPARATION_BETWEEN_UPPER_LETTERS + size_of_upper_letter;
             }
-            offsets[i].0 = offset_x;
-            offsets[i].1 = offset_y;
-            offset_x += Wordle::SEPARATION_BETWEEN_UPPER_LETTERS + UpperLetter::RECT_WIDTH;
-        }
-        let letters: [[UpperLetter; WORDLE_COLS]; WORDLE_ROWS] = [
-            [
-                UpperLetter::new(ctx, offsets[0].0, offsets[0].1),
-                UpperLetter::new(ctx, offsets[1].0, offsets[1].1),
-                UpperLetter::new(ctx, offsets[2].0, offsets[2].1),
-                UpperLetter::new(ctx, offsets[3].0, offsets[3].1),
-                UpperLetter::new(ctx, offsets[4].0, offsets[4].1)
-            ],
-            [
-                UpperLetter::new(ctx, offsets[5].0, offsets[5].1),
-                UpperLetter::new(ctx, offsets[6].0, offsets[6].1),
-                UpperLetter::new(ctx, offsets[7].0, offsets[7].1),
-                UpperLetter::new(ctx, offsets[8].0, offsets[8].1),
-                UpperLetter::new(ctx, offsets[9].0, offsets[9].1)
-            ],
-            [
-                UpperLetter::new(ctx, offsets[10].0, offsets[10].1),
-                UpperLetter::new(ctx, offsets[11].0, offsets[11].1),
-                UpperLetter::new(ctx, offsets[12].0, offsets[12].1),
-                UpperLetter::new(ctx, offsets[13].0, offsets[13].1),
-                UpperLetter::new(ctx, offsets[14].0, offsets[14].1)
-            ],
-            [
-                UpperLetter::new(ctx, offsets[15].0, offsets[15].1),
-                UpperLetter::new(ctx, offsets[16].0, offsets[16].1),
-                UpperLetter::new(ctx, offsets[17].0, offsets[17].1),
-                UpperLetter::new(ctx, offsets[18].0, offsets[18].1),
-                UpperLetter::new(ctx, offsets[19].0, offsets[19].1)
-            ],
-            [
-                UpperLetter::new(ctx, offsets[20].0, offsets[20].1),
-                UpperLetter::new(ctx, offsets[21].0, offsets[21].1),
-                UpperLetter::new(ctx, offsets[22].0, offsets[22].1),
-                UpperLetter::new(ctx, offsets[23].0, offsets[23].1),
-                UpperLetter::new(ctx, offsets[24].0, offsets[24].1)
-            ],
-            [
-                UpperLetter::new(ctx, offsets[25].0, offsets[25].1),
-                UpperLetter::new(ctx, offsets[26].0, offsets[26].1),
-                UpperLetter::new(ctx, offsets[27].0, offsets[27].1),
-                UpperLetter::new(ctx, offsets[28].0, offsets[28].1),
-                UpperLetter::new(ctx, offsets[29].0, offsets[29].1)
-            ]
-        ];
+            letters.push(row);
+            offset_y += Wordle::SEPARATION_BETWEEN_UPPER_LETTERS + size_of_upper_letter;
+        }
 
         let mut offsets: [(f32, f32); WORDLE_LETTERS_COUNT] = [(0.0, 0.0); WORDLE_LETTERS_COUNT];
 
@@ -152,9 +175,9 @@ impl Wordle {
         let mut changing_dx: f32 = initial_dx;
         let mut changing_dy: f32 = initial_dy;
 
-        for i in 0..WORDLE_LETTERS_COUNT {
-            offsets[i].0 = changing_dx;
-            offsets[i].1 = changing_dy;
+        for (i, offset) in offsets.iter_mut().enumerate() {
+            offset.0 = changing_dx;
+            offset.1 = changing_dy;
             changing_dx += LowerLetter::RECT_WIDTH + Wordle::SEPARATION_BETWEEN_LOWEER_LETTERS;
             if i == 9 {
                 changing_dx = initial_dx + LowerLetter::RECT_WIDTH/2.0;
@@ -451,16 +474,28 @@ impl Wordle {
             delete_clicked,
         );
 
-        let answer: [char; WORDLE_COLS] = lua_wrapper::get_random_word();
+        // right of the keyboard, on the row of the enter and delete buttons
+        let hint_button: LowerLetter = LowerLetter::new(
+            ctx,
+            &String::from("HINT"),
+            LowerLetter::RECT_WIDTH*3.0/2.0,
+            LowerLetter::RECT_HEIGHT,
+            initial_dx + 10.0*Wordle::SEPARATION_BETWEEN_LOWEER_LETTERS + 10.0*LowerLetter::RECT_WIDTH,
+            initial_dy + 2.0*Wordle::SEPARATION_BETWEEN_LOWEER_LETTERS + 2.0*LowerLetter::RECT_HEIGHT,
+            LowerLetter::STRING_SIZE,
+            hint_clicked,
+        );
+
+        let stats: Statistics = Statistics::new(ctx, &options);
 
         let curr_letter: (usize, usize) = (0, 0);
 
         let width = LowerLetter::RECT_WIDTH*3.2;
         let height = LowerLetter::RECT_HEIGHT*(8.0/7.0);
 
-        let offset_x = Self::dist_to_left_of_upper_block() + Self::get_width_of_upper_block() / 2.0 - width / 2.0;
-        let offset_y = Self::dist_to_bottom_of_upper_block() + 
-            (Self::dist_to_top_of_lower_block() - Self::dist_to_bottom_of_upper_block()) / 2.0 - height / 2.0;
+        let offset_x = Self::dist_to_left_of_upper_block(&options) + Self::get_width_of_upper_block(&options) / 2.0 - width / 2.0;
+        let offset_y = Self::dist_to_bottom_of_upper_block(&options) + 
+            (Self::dist_to_top_of_lower_block() - Self::dist_to_bottom_of_upper_block(&options)) / 2.0 - height / 2.0;
 
         let text_size = 27.4;
 
@@ -469,10 +504,10 @@ impl Wordle {
             offset_y,
             width,
             height,
-            UpperLetter::RECT_GREEN,
+            theme.correct,
             &String::from("YOU WON"),
             text_size,
-            Color::BLACK,
+            theme.won_text,
             no_animation,
             AnimatedArguments::default(),
         );
@@ -482,29 +517,30 @@ impl Wordle {
             offset_y,
             width,
             height,
-            LowerLetter::RECT_DARKER_GRAY,
-            &answer.iter().collect(),
+            theme.absent_key,
+            &engine.get_answer().iter().collect(),
             text_size,
-            Color::WHITE,
+            theme.button_text,
             no_animation,
             AnimatedArguments::default(),
         );
 
-        let purple: Color = Color { r: 0.25, g: 0.08, b: 0.38, a: 1.0 };
+        // wide enough for the longer messages of the hard mode
+        let invalid_word_width = width * 1.6;
 
         let invalid_word: AnimatedBox = AnimatedBox::new(
-                offset_x,
+                offset_x - (invalid_word_width - width) / 2.0,
                 offset_y,
-                width,
+                invalid_word_width,
                 height,
-                purple,
+                theme.button,
                 &String::from("Invalid word"),
                 21.0,
-                Color::WHITE,
+                theme.button_text,
                 swipe_animation,
                 AnimatedArguments::for_swipe_animation(
                     ctx,
-                    630.0,
+                    swipe_speed,
                     Duration::from_secs_f32(1.25),
                     Duration::from_secs_f32(2.5),
                 ),
@@ -513,72 +549,196 @@ impl Wordle {
         let width = LowerLetter::RECT_WIDTH * 3.0;
         let height = LowerLetter::RECT_HEIGHT * 1.8;
 
-        let offset_x = Self::dist_to_right_of_upper_block() +
-            (super::WINDOW_WIDTH - Self::dist_to_right_of_upper_block()) / 2.0 - width / 2.0;
+        let offset_x = Self::dist_to_right_of_upper_block(&options) +
+            (super::WINDOW_WIDTH - Self::dist_to_right_of_upper_block(&options)) / 2.0 - width / 2.0;
         let offset_y = Self::dist_to_top_of_upper_block() +
-            (Self::dist_to_bottom_of_upper_block() - Self::dist_to_top_of_upper_block()) / 2.0 - height / 2.0;
+            (Self::dist_to_bottom_of_upper_block(&options) - Self::dist_to_top_of_upper_block()) / 2.0 - height / 2.0;
 
         let replay_button: AnimatedBox = AnimatedBox::new(
             offset_x,
             offset_y,
             width,
             height,
-            purple,
+            theme.button,
             &String::from("PLAY AGAIN"),
             21.0,
-            Color::WHITE,
-            roll_animation,
+            theme.button_text,
+            roll,
             AnimatedArguments::for_roll_animation(
                 40.0,
                 utility::Direction::Up,
                 Wordle::dist_to_top_of_upper_block(),
-                Wordle::dist_to_bottom_of_upper_block(),
+                Wordle::dist_to_bottom_of_upper_block(&options),
             ),
         );
 
+        // in the column of the replay button, just under the space it rolls in,
+        // so that the same word can be given to someone else
+        let game_code_box: AnimatedBox = AnimatedBox::new(
+            offset_x,
+            Self::dist_to_bottom_of_upper_block(&options) + height * 0.2,
+            width,
+            height * 0.6,
+            theme.absent_key,
+            &format!("CODE {}", engine.get_game_code().unwrap_or_default()),
+            17.0,
+            theme.button_text,
+            no_animation,
+            AnimatedArguments::default(),
+        );
+
+        // under the game code, so that the result can be posted together with it
+        let share_button: AnimatedBox = AnimatedBox::new(
+            offset_x,
+            Self::dist_to_bottom_of_upper_block(&options) + height * 0.9,
+            width,
+            height * 0.6,
+            theme.button,
+            &String::from("SHARE"),
+            17.0,
+            theme.button_text,
+            no_animation,
+            AnimatedArguments::default(),
+        );
+
+        // in the column of the statistics button, opposite the game code
+        let mut remaining_box: AnimatedBox = AnimatedBox::new(
+            Self::dist_to_left_of_upper_block(&options) / 2.0 - width / 2.0,
+            Self::dist_to_bottom_of_upper_block(&options) + height * 0.2,
+            width,
+            height * 0.6,
+            theme.absent_key,
+            &String::new(),
+            17.0,
+            theme.button_text,
+            no_animation,
+            AnimatedArguments::default(),
+        );
+        if options.show_remaining {
+            remaining_box.put_on_screen();
+        }
+
+        // takes the place of the number of answers left once the game is over
+        let analysis_button: AnimatedBox = AnimatedBox::new(
+            Self::dist_to_left_of_upper_block(&options) / 2.0 - width / 2.0,
+            Self::dist_to_bottom_of_upper_block(&options) + height * 0.2,
+            width,
+            height * 0.6,
+            theme.button,
+            &String::from("ANALYSIS"),
+            17.0,
+            theme.button_text,
+            no_animation,
+            AnimatedArguments::default(),
+        );
+
         let see_stats_button = AnimatedBox::new(
-            Self::dist_to_left_of_upper_block() / 2.0 - width / 2.0,
-            Self::dist_to_top_of_upper_block() + (Self::dist_to_bottom_of_upper_block() - Self::dist_to_top_of_upper_block()) / 2.0 - height / 2.0,
+            Self::dist_to_left_of_upper_block(&options) / 2.0 - width / 2.0,
+            Self::dist_to_top_of_upper_block() + (Self::dist_to_bottom_of_upper_block(&options) - Self::dist_to_top_of_upper_block()) / 2.0 - height / 2.0,
             width,
             height,
-            purple,
+            theme.button,
             &"STATISTICS".to_string(),
             20.0,
-            Color::WHITE,
-            roll_animation,
+            theme.button_text,
+            roll,
             AnimatedArguments::for_roll_animation(
                 40.0,
                 utility::Direction::Down,
                 Wordle::dist_to_top_of_upper_block(),
-                Wordle::dist_to_bottom_of_upper_block(),
+                Wordle::dist_to_bottom_of_upper_block(&options),
             ),
         );
 
-        Wordle{
+        // opposite the share button, always on the screen
+        let mut settings_button: AnimatedBox = AnimatedBox::new(
+            Self::dist_to_left_of_upper_block(&options) / 2.0 - width / 2.0,
+            Self::dist_to_bottom_of_upper_block(&options) + height * 0.9,
+            width,
+            height * 0.6,
+            theme.button,
+            &String::from("SETTINGS"),
+            17.0,
+            theme.button_text,
+            no_animation,
+            AnimatedArguments::default(),
+        );
+        settings_button.put_on_screen();
+
+        let mut wordle = Wordle{
             game_board: letters,
             used_letters,
             enter_button,
             delete_button,
-            answer,
+            hint_button,
+            engine,
+            options,
+            settings: Settings::load(ctx.fs.user_data_dir()),
             curr_letter,
-            game_state: GameState::NotOver,
+            row_being_revealed: None,
             you_won_box,
             shown_answer_box,
             invalid_word,
             replay_button,
-            stats: Statistics::new(),
+            game_code_box,
+            share_button,
+            clipboard: None,
+            remaining_box,
+            analysis_button,
+            analysis: Analysis::default(),
+            stats,
             see_stats_button,
+            settings_button,
+            settings_menu: SettingsMenu::default(),
+            sounds: Sounds::new(ctx),
+        };
+        // today's daily game or a saved game may already have guesses in it
+        if wordle.engine.get_curr_row() != 0 {
+            restore_board(&mut wordle, ctx);
+        }
+        update_remaining_answers(&mut wordle);
+        wordle
+    }
+
+    // keeps the unfinished game for the next start; the daily game is saved after every guess instead
+    pub fn save_game(&self, ctx: &Context) {
+        if self.engine.get_mode() == GameMode::Daily {
+            return;
+        }
+        if self.engine.is_over() {
+            SavedGame::remove(ctx.fs.user_data_dir());
+            return;
+        }
+
+        let partial_row: Vec<char> = (0..self.curr_letter.1)
+            .filter_map(|i| self.game_board[self.curr_letter.0][i].get_value())
+            .collect();
+        // nothing to lose in a game that was not started, so an older saved game is kept
+        if self.engine.get_curr_row() == 0 && partial_row.is_empty() {
+            return;
+        }
+
+        if let Err(err) = SavedGame::from_engine(&self.engine, &partial_row).save(ctx.fs.user_data_dir()) {
+            eprintln!("Could not save the game: {}", err);
         }
     }
 
     pub fn draw_wordle(&mut self, canvas: &mut graphics::Canvas, ctx: &mut Context) -> GameResult {
+        if self.settings_menu.is_on_screen() {
+            let _ = self.settings_menu.draw(ctx, canvas, &self.options);
+            return Ok(());
+        }
         if self.stats.is_on_screen() {
             let _ = self.stats.draw(ctx, canvas);
             return Ok(());
         }
-        for i in 0..WORDLE_ROWS {
-            for j in 0..WORDLE_COLS {
-                let _ = self.game_board[i][j].draw(ctx, canvas);
+        if self.analysis.is_on_screen() {
+            let _ = self.analysis.draw(ctx, canvas);
+            return Ok(());
+        }
+        for row in &self.game_board {
+            for letter in row {
+                let _ = letter.draw(ctx, canvas);
             }
         }
         for i in 0..WORDLE_LETTERS_COUNT {
@@ -586,22 +746,40 @@ impl Wordle {
         }
         let _ = self.enter_button.draw(ctx, canvas);
         let _ = self.delete_button.draw(ctx, canvas);
+        if self.options.hints {
+            let _ = self.hint_button.draw(ctx, canvas);
+        }
 
-        if self.you_won_box.is_on_screen() == true {
+        if self.you_won_box.is_on_screen() {
             let _ = self.you_won_box.draw(ctx, canvas);
         }
-        if self.shown_answer_box.is_on_screen() == true {
+        if self.shown_answer_box.is_on_screen() {
             let _ = self.shown_answer_box.draw(ctx, canvas);
         }
-        if self.invalid_word.is_on_screen() == true {
+        if self.invalid_word.is_on_screen() {
             let _ = self.invalid_word.draw(ctx, canvas);
         }
-        if self.replay_button.is_on_screen() == true {
+        if self.replay_button.is_on_screen() {
             let _ = self.replay_button.draw(ctx, canvas);
         }
+        if self.game_code_box.is_on_screen() {
+            let _ = self.game_code_box.draw(ctx, canvas);
+        }
+        if self.share_button.is_on_screen() {
+            let _ = self.share_button.draw(ctx, canvas);
+        }
+        if self.remaining_box.is_on_screen() {
+            let _ = self.remaining_box.draw(ctx, canvas);
+        }
+        if self.analysis_button.is_on_screen() {
+            let _ = self.analysis_button.draw(ctx, canvas);
+        }
         if self.see_stats_button.is_on_screen() {
             let _ = self.see_stats_button.draw(ctx, canvas);
         }
+        if self.settings_button.is_on_screen() {
+            let _ = self.settings_button.draw(ctx, canvas);
+        }
         Ok(())
     }
 
@@ -618,20 +796,20 @@ impl Wordle {
         let _ = self.see_stats_button.update_animated_box(ctx, args);
         self.see_stats_button.args = args.clone();
 
-        Ok(())
-    }
-
-    fn is_valid_word(word: [char; WORDLE_COLS]) -> bool {
-        let mut temp: String = word.iter().collect();
-        temp = temp.to_lowercase();
-        let all_words = lua_wrapper::get_five_letter_words();
-        let len = all_words.len();
-        for i in 0..len {
-            if temp == all_words[i] {
-                return true;
+        for row in &mut self.game_board {
+            for letter in row {
+                letter.update_animation(ctx);
+            }
+        }
+        if let Some(row) = self.row_being_revealed {
+            if !self.game_board[row].iter().any(|letter| letter.is_animating()) {
+                self.row_being_revealed = None;
+                color_keyboard(self, ctx, row);
+                finish_reveal(self, ctx);
             }
         }
-        false
+
+        Ok(())
     }
 
     fn get_position(&self, ch: char) -> usize {
@@ -646,12 +824,33 @@ impl Wordle {
     }
 
     pub fn detect_click(&mut self, ctx: &mut Context, button: MouseButton, x: f32, y: f32) {
-        if self.stats.quit_stats_screen_request(x, y) {
-            self.stats.remove_from_screen();
+        // nothing under the settings can be clicked while they are open
+        if self.settings_menu.is_on_screen() {
+            if self.settings_menu.quit_settings_screen_request(x, y) {
+                self.settings_menu.remove_from_screen();
+                // a game that was not started yet can as well be the next one
+                if self.engine.get_mode() == GameMode::Random && self.engine.get_curr_row() == 0 && self.curr_letter.1 == 0 {
+                    self.start_new_game(ctx);
+                }
+            }
+            else if let Some(setting) = self.settings_menu.get_clicked_setting(x, y) {
+                self.change_setting(ctx, setting, button != MouseButton::Right);
+            }
+            return;
+        }
+        // only the overlay that is drawn can be closed, the statistics are drawn instead of the analysis
+        if self.stats.is_on_screen() {
+            if self.stats.quit_stats_screen_request(x, y) {
+                self.stats.remove_from_screen();
+                return;
+            }
+        }
+        else if self.analysis.quit_analysis_screen_request(x, y) {
+            self.analysis.remove_from_screen();
             return;
         }
 
-        if self.curr_letter.1 != WORDLE_COLS {
+        if self.curr_letter.1 != self.engine.get_word_length() {
             for curr_tile in &mut self.used_letters.clone() {
                 if curr_tile.point_is_in(x, y) && button == MouseButton::Left {
                     (*curr_tile).update(ctx, self, curr_tile.get_value_char());
@@ -667,12 +866,138 @@ impl Wordle {
             self.enter_button.clone().update(ctx, self, None);
         }
 
+        if self.options.hints && self.hint_button.point_is_in(x, y) {
+            self.hint_button.clone().update(ctx, self, None);
+        }
+
         if self.see_stats_button.point_is_in(x, y) {
             self.stats.put_on_screen();
         }
 
-        if self.replay_button.point_is_in(x, y) && !self.stats.is_on_screen() {
-            *self = Wordle::new(ctx);
+        if self.settings_button.point_is_in(x, y) && !self.stats.is_on_screen() && !self.analysis.is_on_screen() {
+            self.settings_menu.put_on_screen();
+            return;
+        }
+
+        if self.analysis_button.is_on_screen() && self.analysis_button.point_is_in(x, y) && !self.stats.is_on_screen() {
+            self.analysis.analyze(&self.engine);
+            self.analysis.put_on_screen();
+        }
+
+        if self.share_button.is_on_screen() && self.share_button.point_is_in(x, y) && !self.stats.is_on_screen() && !self.analysis.is_on_screen() {
+            self.share_result(ctx);
+        }
+
+        if self.replay_button.point_is_in(x, y) && !self.stats.is_on_screen() && !self.analysis.is_on_screen() {
+            self.start_new_game(ctx);
+        }
+    }
+
+    fn start_new_game(&mut self, ctx: &mut Context) {
+        // the daily game can be played only once a day and a seed or a code would give the same word again,
+        // so the next game is a random one
+        let mut options = self.options.clone();
+        options.mode = GameMode::Random;
+        let clipboard = self.clipboard.take();
+        *self = Wordle::new(ctx, options);
+        self.clipboard = clipboard;
+    }
+
+    // changes the setting to its next value (the previous one if going back) for the next game and saves it for the next start
+    fn change_setting(&mut self, ctx: &Context, setting: Setting, forward: bool) {
+        let cycle = |value: usize, min: usize, max: usize| {
+            if forward {
+                if value >= max { min } else { value + 1 }
+            }
+            else if value <= min { max } else { value - 1 }
+        };
+
+        match setting {
+            Setting::HardMode => self.options.hard_mode = !self.options.hard_mode,
+            Setting::Theme => {
+                let names = Theme::get_names(ctx.fs.user_data_dir());
+                let position = names.iter().position(|name| name.eq_ignore_ascii_case(&self.options.theme)).unwrap_or(0);
+                let next = if forward { (position + 1) % names.len() } else { (position + names.len() - 1) % names.len() };
+                self.options.theme = names[next].clone();
+            },
+            Setting::WordLength => self.options.word_length = cycle(self.options.word_length, MIN_WORD_LENGTH, MAX_WORD_LENGTH),
+            Setting::Guesses => self.options.max_guesses = cycle(self.options.max_guesses, MIN_NUMBER_OF_GUESSES, MAX_NUMBER_OF_GUESSES),
+            Setting::Animations => self.options.animations = !self.options.animations,
+            Setting::Sound => self.options.sound = !self.options.sound,
+        }
+
+        // only the setting that was changed is saved, the others keep their saved values
+        match setting {
+            Setting::HardMode => self.settings.hard_mode = self.options.hard_mode,
+            Setting::Theme => self.settings.theme = self.options.theme.clone(),
+            Setting::WordLength => self.settings.word_length = self.options.word_length,
+            Setting::Guesses => self.settings.max_guesses = self.options.max_guesses,
+            Setting::Animations => self.settings.animations = self.options.animations,
+            Setting::Sound => self.settings.sound = self.options.sound,
+        }
+        if let Err(err) = self.settings.save(ctx.fs.user_data_dir()) {
+            eprintln!("Could not save the settings: {}", err);
+        }
+    }
+
+    // the colors of every guess as emoji squares under a header like "Wordle 3/6", the way the result is posted
+    pub fn get_share_text(&self) -> String {
+        let attempts = if self.engine.get_game_state() == GameState::Won {
+            self.engine.get_curr_row().to_string()
+        }
+        else {
+            String::from("X")
+        };
+        let mut text = String::from("Wordle ");
+        if let Some(date) = self.engine.get_daily_date() {
+            text += &format!("#{} ", daily::get_day_number(date));
+        }
+        text += &format!("{}/{}", attempts, self.engine.get_max_guesses());
+        if self.engine.is_hard_mode() {
+            text.push('*');
+        }
+        text.push('\n');
+
+        for row in &self.game_board[..self.engine.get_curr_row()] {
+            text.push('\n');
+            for letter in row {
+                text.push(match letter.get_state() {
+                    State::CorrectInWord => '🟩',
+                    State::IncorrectInWord => '🟨',
+                    State::NotInWord | State::NotFinalized => '⬛',
+                });
+            }
+        }
+
+        // anyone can play the same word with the code, the daily word is the same for everyone anyway
+        if self.engine.get_daily_date().is_none() {
+            if let Some(code) = self.engine.get_game_code() {
+                text += &format!("\n\nCode {}", code);
+            }
+        }
+        text
+    }
+
+    // copies the result to the clipboard, or writes it to a file in the data directory if there is no clipboard
+    fn share_result(&mut self, ctx: &Context) {
+        let text = self.get_share_text();
+
+        if self.clipboard.is_none() {
+            self.clipboard = arboard::Clipboard::new().ok();
+        }
+        let copied = match &mut self.clipboard {
+            Some(clipboard) => clipboard.set_text(text.clone()).is_ok(),
+            None => false,
+        };
+        if copied {
+            self.share_button.set_text("COPIED");
+            return;
+        }
+
+        let path = ctx.fs.user_data_dir().join(Self::SHARE_FILE_NAME);
+        match assets::write_atomically(&path, &text) {
+            Ok(_) => self.share_button.set_text("SAVED TO FILE"),
+            Err(err) => eprintln!("Could not save the result to {}: {}", path.display(), err),
         }
     }
 
@@ -710,7 +1035,7 @@ impl Wordle {
     }
 
     pub fn detect_typing(&mut self, ctx: &mut Context, input: KeyInput) {
-        if self.curr_letter.1 != WORDLE_COLS && Wordle::get_value_of_typed_key(input).is_some() {
+        if self.curr_letter.1 != self.engine.get_word_length() && Wordle::get_value_of_typed_key(input).is_some() {
             let value_typed: char = Wordle::get_value_of_typed_key(input).unwrap();
             for curr_tile in &mut self.used_letters.clone() {
                 let value_of_current_letter = curr_tile.get_value_char();
@@ -731,48 +1056,69 @@ impl Wordle {
 }
 
 fn letter_clicked(ctx: &mut Context, wordle: &mut Wordle, value: Option<char>) {
-    if wordle.curr_letter.1 == WORDLE_COLS {
+    if wordle.curr_letter.1 == wordle.engine.get_word_length() || wordle.row_being_revealed.is_some() {
         return;
     }
     wordle.game_board[wordle.curr_letter.0][wordle.curr_letter.1].set_letter (ctx, value.unwrap());
+    if wordle.options.animations {
+        wordle.game_board[wordle.curr_letter.0][wordle.curr_letter.1].pop(ctx);
+    }
     wordle.curr_letter.1 += 1;
+    if wordle.options.sound {
+        wordle.sounds.play_key(ctx);
+    }
 }
 
 fn enter_clicked(ctx: &mut Context, wordle: &mut Wordle, _value: Option<char>) {
-    if wordle.curr_letter.1 != WORDLE_COLS {
-        if !wordle.invalid_word.is_on_screen() {
-            wordle.invalid_word.put_on_screen();
-        }
+    if wordle.row_being_revealed.is_some() {
         return;
     }
-    let mut curr_word: [char; WORDLE_COLS] = [' '; WORDLE_COLS];
-
-    for i in 0..WORDLE_COLS {
-        curr_word[i] = wordle.game_board[wordle.curr_letter.0][i].get_value().unwrap();
+    let mut curr_word: Vec<char> = Vec::with_capacity(wordle.engine.get_word_length());
+    for i in 0..wordle.curr_letter.1 {
+        curr_word.push(wordle.game_board[wordle.curr_letter.0][i].get_value().unwrap());
     }
 
-    if !Wordle::is_valid_word(curr_word) {
-        if !wordle.invalid_word.is_on_screen() {
-            wordle.invalid_word.put_on_screen();
+    let cond = match wordle.engine.submit_guess(&curr_word) {
+        Ok(cond) => cond,
+        Err(err) => {
+            if !wordle.invalid_word.is_on_screen() {
+                wordle.invalid_word.set_text(&err.to_string());
+                wordle.invalid_word.put_on_screen();
+            }
+            if wordle.options.sound {
+                wordle.sounds.play_invalid_word(ctx);
+            }
+            if wordle.options.animations {
+                for letter in &mut wordle.game_board[wordle.curr_letter.0] {
+                    letter.shake(ctx);
+                }
+            }
+            return;
         }
-        return; 
+    };
+
+    // today's guesses are kept so that the daily game cannot be played again
+    if let Some(date) = wordle.engine.get_daily_date() {
+        daily::save_progress(&wordle.engine, date);
+    }
+
+    if wordle.options.sound {
+        wordle.sounds.play_enter(ctx);
     }
 
-    let cond = scan_entered_word(wordle, ctx, curr_word);
+    if wordle.options.animations {
+        flip_row(wordle, ctx, wordle.curr_letter.0);
+    }
+    else {
+        reveal_row(wordle, ctx, wordle.curr_letter.0);
+        finish_reveal(wordle, ctx);
+    }
 
     if cond == GameState::Won || cond == GameState::Lost {
-        if cond == GameState::Won {
-            wordle.game_state = GameState::Won;
-            wordle.you_won_box.put_on_screen();
-            wordle.stats.update_stats(Some(wordle.curr_letter.0 as u32 + 1));
-        }
-        else if cond == GameState::Lost {
-            wordle.game_state = GameState::Lost;
-            wordle.shown_answer_box.put_on_screen();
-            wordle.stats.update_stats(None);
-        }
-        wordle.replay_button.put_on_screen();
-        wordle.see_stats_button.put_on_screen();
+        wordle.stats.update_stats(&wordle.engine);
+        if wordle.engine.get_mode() != GameMode::Daily {
+            SavedGame::remove(ctx.fs.user_data_dir());
+        }
         return;
     }
     wordle.curr_letter.1 = 0;
@@ -780,7 +1126,7 @@ fn enter_clicked(ctx: &mut Context, wordle: &mut Wordle, _value: Option<char>) {
 }
 
 fn delete_clicked(_ctx: &mut Context, wordle: &mut Wordle, _value: Option<char>) {
-    if wordle.game_state == GameState::Lost || wordle.game_state == GameState::Won {
+    if wordle.engine.is_over() || wordle.row_being_revealed.is_some() {
         return;
     }
     if wordle.curr_letter.1 != 0 {
@@ -789,70 +1135,116 @@ fn delete_clicked(_ctx: &mut Context, wordle: &mut Wordle, _value: Option<char>)
     }
 }
 
-fn scan_entered_word(wordle: &mut Wordle, ctx: &mut Context, word: [char; WORDLE_COLS]) -> GameState {
-    let mut temp: String = word.iter().collect();
-    temp = temp.to_lowercase();
-    let mut ans: String = wordle.answer.iter().collect();
-    ans = ans.to_lowercase();
+// replaces the letters typed in the current row with the guess the solver finds best
+fn hint_clicked(ctx: &mut Context, wordle: &mut Wordle, _value: Option<char>) {
+    if wordle.engine.is_over() || wordle.row_being_revealed.is_some() {
+        return;
+    }
+    let suggestion = match Solver::from_engine(&wordle.engine, Strategy::Entropy).suggest() {
+        Some(suggestion) => suggestion,
+        None => return,
+    };
 
-    let mut indedxes_of_taken_letters_of_answer: [bool; WORDLE_COLS] = [false; WORDLE_COLS];
-    let mut indedxes_of_taken_letters_of_guess: [bool; WORDLE_COLS] = [false; WORDLE_COLS];
+    while wordle.curr_letter.1 != 0 {
+        delete_clicked(ctx, wordle, None);
+    }
+    for ch in suggestion {
+        letter_clicked(ctx, wordle, Some(ch));
+    }
+}
 
-    // searching for the greens in both
-    for i in 0..WORDLE_COLS {
-        if word[i] == wordle.answer[i] {
-            wordle.game_board[wordle.curr_letter.0][i].set_state(ctx, utility::State::CorrectInWord);
-            wordle.used_letters[wordle.get_position(word[i])].set_state(ctx, utility::State::CorrectInWord);
-            indedxes_of_taken_letters_of_guess[i] = true;
-            indedxes_of_taken_letters_of_answer[i] = true;
-        }
+fn update_remaining_answers(wordle: &mut Wordle) {
+    if !wordle.options.show_remaining {
+        return;
     }
+    let remaining = wordle.engine.get_number_of_remaining_answers();
+    let text = if remaining == 1 { String::from("1 WORD LEFT") } else { format!("{} WORDS LEFT", remaining) };
+    wordle.remaining_box.set_text(&text);
+}
 
-    // searching for the grays in guess
-    for i in 0..WORDLE_COLS {
-        let mut curr_letter_in_guess_is_gray: bool = true;
-        for j in 0..WORDLE_COLS {
-            if word[i] == wordle.answer[j] {
-                curr_letter_in_guess_is_gray = false;
-            }
-        }
-        if curr_letter_in_guess_is_gray == true {
-            wordle.game_board[wordle.curr_letter.0][i].set_state(ctx, utility::State::NotInWord);
-            wordle.used_letters[wordle.get_position(word[i])].set_state(ctx, utility::State::NotInWord);
-            indedxes_of_taken_letters_of_guess[i] = true;
-        }
+fn show_end_of_game(wordle: &mut Wordle) {
+    if wordle.engine.get_game_state() == GameState::Won {
+        wordle.you_won_box.put_on_screen();
+    }
+    else {
+        wordle.shown_answer_box.put_on_screen();
     }
+    wordle.replay_button.put_on_screen();
+    wordle.remaining_box.remove_from_screen();
+    wordle.analysis_button.put_on_screen();
+    if wordle.engine.get_game_code().is_some() {
+        wordle.game_code_box.put_on_screen();
+    }
+    wordle.share_button.put_on_screen();
+    wordle.see_stats_button.put_on_screen();
+}
 
-    // searching for the yellows in both
-    for i in 0..WORDLE_COLS {
-        for j in 0..WORDLE_COLS {
-            if word[i] == wordle.answer[j] {
-                if indedxes_of_taken_letters_of_guess[i] == false && indedxes_of_taken_letters_of_answer[j] == false {
-                    wordle.game_board[wordle.curr_letter.0][i].set_state(ctx, utility::State::IncorrectInWord);
-                    wordle.used_letters[wordle.get_position(word[i])].set_state(ctx, utility::State::IncorrectInWord);
-                    indedxes_of_taken_letters_of_guess[i] = true;
-                    indedxes_of_taken_letters_of_answer[j] = true;
-                }
-            }
+// puts the guesses the engine already has on the board, without counting the game in the statistics again
+fn restore_board(wordle: &mut Wordle, ctx: &mut Context) {
+    let rows = wordle.engine.get_curr_row();
+    for row in 0..rows {
+        let word = wordle.engine.get_guesses()[row].clone();
+        for (i, ch) in word.iter().enumerate() {
+            wordle.game_board[row][i].set_letter(ctx, *ch);
         }
+        reveal_row(wordle, ctx, row);
     }
 
-    // searching for the missed (repeating) letters
-    for i in 0..WORDLE_COLS {
-        if indedxes_of_taken_letters_of_guess[i] == false {
-            wordle.game_board[wordle.curr_letter.0][i].set_state(ctx, utility::State::NotInWord);
-            wordle.used_letters[wordle.get_position(word[i])].set_state(ctx, utility::State::NotInWord);
-            indedxes_of_taken_letters_of_guess[i] = true;
-        }
+    if wordle.engine.is_over() {
+        // a full row keeps the keyboard from typing
+        wordle.curr_letter = (rows - 1, wordle.engine.get_word_length());
+        show_end_of_game(wordle);
     }
+    else {
+        wordle.curr_letter = (rows, 0);
+    }
+}
 
-    if ans == temp {
-        return GameState::Won;
+// colors the tiles of an entered row and the keyboard by the feedback of the engine, all at once
+fn reveal_row(wordle: &mut Wordle, ctx: &mut Context, row: usize) {
+    let feedback = wordle.engine.get_feedback()[row];
+    for (i, letter) in wordle.game_board[row].iter_mut().enumerate() {
+        letter.set_state(ctx, feedback.get_state(i));
     }
-    else if wordle.curr_letter.0 != WORDLE_ROWS - 1 {
-        return GameState::NotOver;
+    color_keyboard(wordle, ctx, row);
+}
+
+// turns the tiles of an entered row over one after another; the keyboard and the rest are shown
+// once the last one is turned, see update_wordle
+fn flip_row(wordle: &mut Wordle, ctx: &mut Context, row: usize) {
+    let feedback = wordle.engine.get_feedback()[row];
+    for (i, letter) in wordle.game_board[row].iter_mut().enumerate() {
+        letter.flip_to_state(ctx, feedback.get_state(i), UpperLetter::DELAY_BETWEEN_FLIPS * i as f32);
     }
-    else {
-        return GameState::Lost;
+    wordle.row_being_revealed = Some(row);
+}
+
+fn color_keyboard(wordle: &mut Wordle, ctx: &mut Context, row: usize) {
+    let word = wordle.engine.get_guesses()[row].clone();
+    for ch in word {
+        let position = wordle.get_position(ch);
+        let letter_state = wordle.engine.get_letter_state(ch);
+        wordle.used_letters[position].set_state(ctx, letter_state);
+    }
+}
+
+// what comes after the colors of a guess: the number of answers left and the end of the game
+fn finish_reveal(wordle: &mut Wordle, ctx: &mut Context) {
+    update_remaining_answers(wordle);
+    if !wordle.engine.is_over() {
+        return;
+    }
+
+    if wordle.engine.get_game_state() == GameState::Won {
+        if wordle.options.sound {
+            wordle.sounds.play_won(ctx);
+        }
+        if wordle.options.animations {
+            let row = wordle.engine.get_curr_row() - 1;
+            for (i, letter) in wordle.game_board[row].iter_mut().enumerate() {
+                letter.bounce(ctx, UpperLetter::DELAY_BETWEEN_BOUNCES * i as f32);
+            }
+        }
     }
+    show_end_of_game(wordle);
 }
@@ -0,0 +1,87 @@
+use std::f32::consts::PI;
+
+use ggez::{audio::{self, SoundSource}, Context};
+
+const SAMPLE_RATE: u32 = 22050;
+
+// the short tones of the game, made when it starts so that there are no sound files to ship with it
+pub struct Sounds {
+    key: Option<audio::Source>,
+    enter: Option<audio::Source>,
+    invalid_word: Option<audio::Source>,
+    won: Option<audio::Source>,
+}
+
+impl Sounds {
+    pub fn new(ctx: &Context) -> Sounds {
+        Sounds {
+            key: make_source(ctx, &make_tone(&[660.0], 0.05)),
+            enter: make_source(ctx, &make_tone(&[440.0], 0.12)),
+            invalid_word: make_source(ctx, &make_tone(&[220.0, 196.0], 0.25)),
+            won: make_source(ctx, &make_tone(&[523.0, 659.0, 784.0], 0.45)),
+        }
+    }
+
+    pub fn play_key(&mut self, ctx: &Context) {
+        play(&mut self.key, ctx);
+    }
+
+    pub fn play_enter(&mut self, ctx: &Context) {
+        play(&mut self.enter, ctx);
+    }
+
+    pub fn play_invalid_word(&mut self, ctx: &Context) {
+        play(&mut self.invalid_word, ctx);
+    }
+
+    pub fn play_won(&mut self, ctx: &Context) {
+        play(&mut self.won, ctx);
+    }
+}
+
+// a sound that cannot be played is left out instead of stopping the game
+fn play(source: &mut Option<audio::Source>, ctx: &Context) {
+    if let Some(source) = source {
+        let _ = source.play_detached(ctx);
+    }
+}
+
+fn make_source(ctx: &Context, wav: &[u8]) -> Option<audio::Source> {
+    match audio::Source::from_data(ctx, audio::SoundData::from_bytes(wav)) {
+        Ok(source) => Some(source),
+        Err(err) => {
+            eprintln!("Could not make a sound: {}", err);
+            None
+        },
+    }
+}
+
+// a mono 16-bit wav of the notes played one after another, each fading out so that it does not click at its end
+fn make_tone(frequencies: &[f32], seconds: f32) -> Vec<u8> {
+    let samples_per_note = (SAMPLE_RATE as f32 * seconds / frequencies.len() as f32) as u32;
+    let number_of_samples = samples_per_note * frequencies.len() as u32;
+
+    let mut wav: Vec<u8> = Vec::with_capacity(44 + 2 * number_of_samples as usize);
+    wav.extend_from_slice(b"RIFF");
+    wav.extend_from_slice(&(36 + 2 * number_of_samples).to_le_bytes());
+    wav.extend_from_slice(b"WAVEfmt ");
+    wav.extend_from_slice(&16u32.to_le_bytes());
+    wav.extend_from_slice(&1u16.to_le_bytes());  // pcm
+    wav.extend_from_slice(&1u16.to_le_bytes());  // one channel
+    wav.extend_from_slice(&SAMPLE_RATE.to_le_bytes());
+    wav.extend_from_slice(&(SAMPLE_RATE * 2).to_le_bytes());  // bytes per second
+    wav.extend_from_slice(&2u16.to_le_bytes());  // bytes per sample
+    wav.extend_from_slice(&16u16.to_le_bytes());  // bits per sample
+    wav.extend_from_slice(b"data");
+    wav.extend_from_slice(&(2 * number_of_samples).to_le_bytes());
+
+    for frequency in frequencies {
+        for i in 0..samples_per_note {
+            let time = i as f32 / SAMPLE_RATE as f32;
+            let volume = 0.25 * (1.0 - i as f32 / samples_per_note as f32);
+            let sample = volume * (2.0 * PI * frequency * time).sin() * i16::MAX as f32;
+            wav.extend_from_slice(&(sample as i16).to_le_bytes());
+        }
+    }
+    wav
+}
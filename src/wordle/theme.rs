@@ -0,0 +1,193 @@
+use std::{collections::HashMap, fs, path::{Path, PathBuf}, sync::RwLock};
+
+use ggez::graphics::Color;
+
+// every color the window is drawn with
+#[derive(Copy, Clone, Debug)]
+#[derive(PartialEq)]
+pub struct Theme {
+    pub background: Color,
+    // the letters of the board and the text of the statistics and the analysis
+    pub letter: Color,
+    pub correct: Color,
+    pub present: Color,
+    // the outline of a tile that is not entered yet
+    pub empty_tile: Color,
+    pub absent_tile: Color,
+    pub unused_key: Color,
+    pub absent_key: Color,
+    pub key_letter: Color,
+    pub button: Color,
+    pub button_text: Color,
+    // the text of the box shown over the board when the game is won
+    pub won_text: Color,
+    // the background of the statistics and the analysis
+    pub panel: Color,
+    pub bar: Color,
+    pub closing_button: Color,
+}
+
+// the theme everything is drawn with at the moment, changed when a game is started with another one
+static CURRENT_THEME: RwLock<Theme> = RwLock::new(Theme::DARK);
+
+impl Theme {
+    pub const DARK: Theme = Theme {
+        background: Color::new(0.08, 0.08, 0.08, 1.0),
+        letter: Color::WHITE,
+        correct: Color::new(0.0, 0.6, 0.0, 1.0),
+        present: Color::new(0.6, 0.6, 0.0, 1.0),
+        empty_tile: Color::new(0.275, 0.275, 0.275, 1.0),
+        absent_tile: Color::new(0.35, 0.35, 0.35, 1.0),
+        unused_key: Color::new(0.455, 0.455, 0.455, 1.0),
+        absent_key: Color::new(0.145, 0.145, 0.145, 1.0),
+        key_letter: Color::WHITE,
+        button: Color::new(0.25, 0.08, 0.38, 1.0),
+        button_text: Color::WHITE,
+        won_text: Color::BLACK,
+        panel: Color::new(0.15, 0.0, 0.23, 1.0),
+        bar: Color::BLUE,
+        closing_button: Color::RED,
+    };
+
+    pub const LIGHT: Theme = Theme {
+        background: Color::new(0.97, 0.97, 0.97, 1.0),
+        letter: Color::new(0.1, 0.1, 0.1, 1.0),
+        correct: Color::new(0.42, 0.67, 0.39, 1.0),
+        present: Color::new(0.79, 0.71, 0.35, 1.0),
+        empty_tile: Color::new(0.83, 0.84, 0.85, 1.0),
+        absent_tile: Color::new(0.7, 0.7, 0.72, 1.0),
+        unused_key: Color::new(0.83, 0.84, 0.85, 1.0),
+        absent_key: Color::new(0.47, 0.49, 0.5, 1.0),
+        key_letter: Color::new(0.1, 0.1, 0.1, 1.0),
+        button: Color::new(0.52, 0.36, 0.67, 1.0),
+        button_text: Color::WHITE,
+        won_text: Color::WHITE,
+        panel: Color::new(0.9, 0.86, 0.95, 1.0),
+        bar: Color::new(0.35, 0.45, 0.85, 1.0),
+        closing_button: Color::new(0.8, 0.1, 0.1, 1.0),
+    };
+
+    // orange and blue instead of green and yellow, which are hard to tell apart for many people
+    pub const COLOR_BLIND: Theme = Theme {
+        correct: Color::new(0.96, 0.47, 0.23, 1.0),
+        present: Color::new(0.52, 0.75, 0.98, 1.0),
+        bar: Color::new(0.3, 0.3, 0.3, 1.0),
+        ..Theme::DARK
+    };
+
+    pub const BUILT_IN: [(&'static str, Theme); 3] = [
+        ("dark", Theme::DARK),
+        ("light", Theme::LIGHT),
+        ("colorblind", Theme::COLOR_BLIND),
+    ];
+
+    const THEMES_DIR_NAME: &'static str = "themes";
+
+    pub fn get() -> Theme {
+        *CURRENT_THEME.read().unwrap()
+    }
+
+    pub fn set(theme: Theme) {
+        *CURRENT_THEME.write().unwrap() = theme;
+    }
+
+    // a built-in theme, a theme from the themes directory of the data directory (`themes/NAME.json`)
+    // or a theme from the path of a json file; the dark theme if there is no such theme
+    pub fn load(name: &str, data_dir: &Path) -> Theme {
+        if let Some((_, theme)) = Theme::BUILT_IN.iter().find(|(built_in, _)| built_in.eq_ignore_ascii_case(name)) {
+            return *theme;
+        }
+
+        let path = if name.ends_with(".json") {
+            PathBuf::from(name)
+        }
+        else {
+            data_dir.join(Theme::THEMES_DIR_NAME).join(format!("{}.json", name))
+        };
+        match fs::read_to_string(&path) {
+            Ok(contents) => Theme::from_json(&contents).unwrap_or_else(|err| {
+                eprintln!("Could not read the theme {}: {}", path.display(), err);
+                Theme::DARK
+            }),
+            Err(err) => {
+                eprintln!("Could not read the theme {}: {}", path.display(), err);
+                Theme::DARK
+            },
+        }
+    }
+
+    // the names of the built-in themes and then of the themes in the themes directory, in the order of the settings
+    pub fn get_names(data_dir: &Path) -> Vec<String> {
+        let mut names: Vec<String> = Theme::BUILT_IN.iter().map(|(name, _)| name.to_string()).collect();
+
+        let mut custom_names: Vec<String> = fs::read_dir(data_dir.join(Theme::THEMES_DIR_NAME))
+            .map(|entries| entries
+                .filter_map(|entry| entry.ok())
+                .map(|entry| entry.path())
+                .filter(|path| path.extension().is_some_and(|extension| extension == "json"))
+                .filter_map(|path| path.file_stem().map(|stem| stem.to_string_lossy().to_string()))
+                .collect())
+            .unwrap_or_default();
+        custom_names.sort();
+        names.extend(custom_names);
+        names
+    }
+
+    // a json object of colors such as `{"base": "light", "correct": "#6aaa64"}`, the colors that are not in it
+    // are the ones of the base theme (the dark one if there is none)
+    pub fn from_json(contents: &str) -> Result<Theme, String> {
+        let fields: HashMap<String, String> = serde_json::from_str(contents).map_err(|err| err.to_string())?;
+
+        let mut theme = match fields.get("base") {
+            Some(base) => Theme::BUILT_IN.iter()
+                .find(|(built_in, _)| built_in.eq_ignore_ascii_case(base))
+                .map(|(_, theme)| *theme)
+                .ok_or(format!("unknown base theme {}", base))?,
+            None => Theme::DARK,
+        };
+
+        for (name, value) in &fields {
+            if name == "base" {
+                continue;
+            }
+            let color = parse_color(value).ok_or(format!("invalid color {} of {}", value, name))?;
+            match theme.get_color_mut(name) {
+                Some(field) => *field = color,
+                None => return Err(format!("unknown color {}", name)),
+            }
+        }
+        Ok(theme)
+    }
+
+    fn get_color_mut(&mut self, name: &str) -> Option<&mut Color> {
+        match name {
+            "background" => Some(&mut self.background),
+            "letter" => Some(&mut self.letter),
+            "correct" => Some(&mut self.correct),
+            "present" => Some(&mut self.present),
+            "empty_tile" => Some(&mut self.empty_tile),
+            "absent_tile" => Some(&mut self.absent_tile),
+            "unused_key" => Some(&mut self.unused_key),
+            "absent_key" => Some(&mut self.absent_key),
+            "key_letter" => Some(&mut self.key_letter),
+            "button" => Some(&mut self.button),
+            "button_text" => Some(&mut self.button_text),
+            "won_text" => Some(&mut self.won_text),
+            "panel" => Some(&mut self.panel),
+            "bar" => Some(&mut self.bar),
+            "closing_button" => Some(&mut self.closing_button),
+            _ => None,
+        }
+    }
+}
+
+// `#rrggbb` or `#rrggbbaa`, the `#` may be left out
+fn parse_color(value: &str) -> Option<Color> {
+    let hex = value.trim().trim_start_matches('#');
+    let number = u32::from_str_radix(hex, 16).ok()?;
+    match hex.len() {
+        6 => Some(Color::from_rgb_u32(number)),
+        8 => Some(Color::from_rgba_u32(number)),
+        _ => None,
+    }
+}
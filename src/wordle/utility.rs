@@ -1,61 +1,6 @@
-pub mod lua_wrapper {
-    use rlua::{ Function, Lua, Table, Value };
-
-    pub fn get_five_letter_words() -> Vec<String> {
-        let lua = Lua::new();
-
-        let lua_script_content = std::fs::read_to_string("src/wordle/words.lua")
-                                        .expect("Error when opening words.lua");
-        
-        // defined outside the context scope
-        let mut result: Vec<String> = Vec::new();
-
-        lua.context(|lua_ctx| {
-            lua_ctx.load(&lua_script_content).exec().unwrap();
-            let lua_function: Function = lua_ctx.globals().get("get_all_words").unwrap();
-            let table: Table = lua_function.call(()).unwrap();
-
-            let mut ctr: i64 = 1;
-            
-            loop {
-                match table.get::<Value, String>(rlua::Value::Integer(ctr)) {
-                    Err(_err) => break,
-                    Ok(value) => result.push(value),
-                }
-                ctr += 1;
-            }
-        });
-        result
-    }
-
-    pub fn get_random_word() -> [char; crate::wordle::WORDLE_COLS] {
-        let lua = Lua::new();
-
-        let lua_script_content = std::fs::read_to_string("src/wordle/words.lua")
-                                        .expect("Error when opening words.lua");
-        
-        // defined outside the context scope
-        let mut result: [char; crate::wordle::WORDLE_COLS] = [' '; crate::wordle::WORDLE_COLS];
-
-        lua.context(|lua_ctx| {
-            lua_ctx.load(&lua_script_content).exec().unwrap();
-            let lua_function: Function = lua_ctx.globals().get("get_random_word").unwrap();
-            let str: String = lua_function.call(()).unwrap();
-            let str = str.to_uppercase();
-
-            let mut i: usize = 0;
-            for ch in str.chars() {
-                result[i] = ch;
-                i += 1;
-            }
-        });
-        result
-    }
-}
-
 use std::time::Duration;
-use std::fs::{File, OpenOptions};
-use std::io::{Read, Write};
+use std::path::PathBuf;
+use std::f32::consts::PI;
 use rand::Rng;
 
 use ggez::{mint::Point2, graphics::{Rect, DrawParam}};
@@ -66,31 +11,26 @@ pub use ggez::{
     GameResult,
 };
 
-#[derive(Copy, Clone)]
-#[derive(PartialEq, PartialOrd)]
-pub enum State {
-    NotFinalized,
-    NotInWord,
-    IncorrectInWord,
-    CorrectInWord,
-}
+pub use crate::engine::State;
+use super::theme::Theme;
+use crate::engine::{daily, history::{self, GameRecord}, solver::{self, GuessAnalysis, Strategy}, stats::GameStats, GameEngine, GameMode, GameOptions, GameState};
 
 impl State {
-    fn get_color_of_upper_letter(state: &State) -> Color {
-        return match state {
-            State::NotFinalized => UpperLetter::RECT_DARKER_GRAY,
-            State::NotInWord => UpperLetter::RECT_BRIGHTER_GRAY,
-            State::IncorrectInWord => UpperLetter::RECT_YELLOW,
-            State::CorrectInWord => UpperLetter::RECT_GREEN,
+    fn get_color_of_upper_letter(state: &State, theme: &Theme) -> Color {
+        match state {
+            State::NotFinalized => theme.empty_tile,
+            State::NotInWord => theme.absent_tile,
+            State::IncorrectInWord => theme.present,
+            State::CorrectInWord => theme.correct,
         }
     }
 
-    fn get_color_of_lower_letter(state: &State) -> Color {
-        return match state {
-            State::NotFinalized => LowerLetter::RECT_BRIGHTER_GRAY,
-            State::NotInWord => LowerLetter::RECT_DARKER_GRAY,
-            State::IncorrectInWord => LowerLetter::RECT_YELLOW,
-            State::CorrectInWord => LowerLetter::RECT_GREEN,
+    fn get_color_of_lower_letter(state: &State, theme: &Theme) -> Color {
+        match state {
+            State::NotFinalized => theme.unused_key,
+            State::NotInWord => theme.absent_key,
+            State::IncorrectInWord => theme.present,
+            State::CorrectInWord => theme.correct,
         }
     }
 }
@@ -99,80 +39,165 @@ pub struct UpperLetter {
     rect: graphics::Rect,
     letter: Option<Text>,
     state: State,
+    // how the tile is drawn at the moment of its animation, around the center of its rect
+    scale: Vec2,
+    offset: Vec2,
+    // the state a flipping tile shows once it is turned half way
+    pending_state: Option<State>,
+    animation: Option<(TileAnimationFunction, AnimatedArguments)>,
 }
 
 impl UpperLetter {
     pub const RECT_WIDTH: f32 = 57.6;
     pub const RECT_HEIGHT: f32 = 57.6;
-    pub const RECT_DARKER_GRAY: Color = Color::new(0.275, 0.275, 0.275, 1.0);
-    pub const RECT_BRIGHTER_GRAY: Color = Color::new(0.35, 0.35, 0.35, 1.0);
-    pub const RECT_YELLOW: Color = Color::new(0.6, 0.6, 0.0, 1.0);
-    pub const RECT_GREEN: Color = Color::new(0.0, 0.6, 0.0, 1.0);
 
     pub const LETTER_SIZE: f32 = 53.6;
-    pub const LETTER_COLOR: Color = Color::WHITE;
+
+    // in seconds
+    pub const DELAY_BETWEEN_FLIPS: f32 = 0.25;
+    pub const DELAY_BETWEEN_BOUNCES: f32 = 0.1;
+    const FLIP_DURATION: f32 = 0.4;
+    const POP_DURATION: f32 = 0.1;
+    const SHAKE_DURATION: f32 = 0.5;
+    const BOUNCE_DURATION: f32 = 0.5;
 
     pub fn new(
         _ctx: &mut Context,
         offset_x: f32,
-        offset_y: f32
+        offset_y: f32,
+        size: f32,
     ) -> UpperLetter {
-        let rect = graphics::Rect::new(offset_x, offset_y, UpperLetter::RECT_WIDTH, UpperLetter::RECT_HEIGHT);
+        let rect = graphics::Rect::new(offset_x, offset_y, size, size);
         UpperLetter {
             rect,
             letter: None,
             state: State::NotFinalized,
+            scale: Vec2::ONE,
+            offset: Vec2::ZERO,
+            pending_state: None,
+            animation: None,
         }
     }
 
     pub fn draw(&self, ctx: &mut Context, canvas: &mut graphics::Canvas) -> GameResult {
-        let color = State::get_color_of_upper_letter(&self.state);
+        let color = State::get_color_of_upper_letter(&self.state, &Theme::get());
         let mode: graphics::DrawMode = match self.state {
             State::NotInWord => graphics::DrawMode::fill(),
             State::NotFinalized => graphics::DrawMode::stroke(4.0),
             _ => graphics::DrawMode::stroke(3.2),
         };
+
+        // a tile flipped to its edge is still drawn as a thin line
+        let scale = Vec2::new(self.scale.x, self.scale.y.max(0.02));
+        let center = Vec2::new(self.rect.x + self.rect.w/2.0, self.rect.y + self.rect.h/2.0) + self.offset;
+        let drawn_rect = graphics::Rect::new(
+            center.x - self.rect.w*scale.x/2.0,
+            center.y - self.rect.h*scale.y/2.0,
+            self.rect.w*scale.x,
+            self.rect.h*scale.y,
+        );
         let rect: graphics::Mesh = graphics::Mesh::new_rectangle(
             ctx,
             mode,
-            self.rect,
+            drawn_rect,
             color,
         ).unwrap();
 
         canvas.draw(&rect, graphics::DrawParam::default());
         
-        if self.letter.is_some() {
-            let letter_width = self.letter.as_ref().unwrap().measure(ctx).unwrap().x;
-            let letter_height = self.letter.as_ref().unwrap().measure(ctx).unwrap().y;
-            canvas.draw(self.letter.as_ref().unwrap(), Vec2::new(
-                self.rect.x + self.rect.w/2.0 - letter_width/2.0,
-                self.rect.y + self.rect.h/2.0 - letter_height/2.0,
-            )
+        if let Some(letter) = &self.letter {
+            let letter_width = letter.measure(ctx).unwrap().x;
+            let letter_height = letter.measure(ctx).unwrap().y;
+            canvas.draw(letter, DrawParam::default()
+                .dest(Vec2::new(
+                    center.x - letter_width*scale.x/2.0,
+                    center.y - letter_height*scale.y/2.0,
+                ))
+                .scale(scale)
             );
         }
         Ok(())
     }
 
+    // the animation takes the place of the one the tile had
+    pub fn animate(&mut self, animation: TileAnimationFunction, args: AnimatedArguments) {
+        if let Some(state) = self.pending_state.take() {
+            self.state = state;
+        }
+        self.scale = Vec2::ONE;
+        self.offset = Vec2::ZERO;
+        self.animation = Some((animation, args));
+    }
+
+    pub fn update_animation(&mut self, ctx: &mut Context) {
+        let (animation, mut args) = match self.animation.take() {
+            Some(animation) => animation,
+            None => return,
+        };
+        animation(self, ctx, &mut args);
+
+        if get_progress_of_animation(ctx, &args) < 1.0 {
+            self.animation = Some((animation, args));
+        }
+        else {
+            // the tile ends up as it was, only in its new state
+            if let Some(state) = self.pending_state.take() {
+                self.state = state;
+            }
+            self.scale = Vec2::ONE;
+            self.offset = Vec2::ZERO;
+        }
+    }
+
+    pub fn is_animating(&self) -> bool {
+        self.animation.is_some()
+    }
+
+    // the tile turns over after the delay and shows the state on its other side
+    pub fn flip_to_state(&mut self, ctx: &Context, state: State, delay: f32) {
+        self.animate(flip_animation, AnimatedArguments::for_tile_animation(ctx, 0.0, delay, UpperLetter::FLIP_DURATION));
+        self.pending_state = Some(state);
+    }
+
+    pub fn pop(&mut self, ctx: &Context) {
+        self.animate(pop_animation, AnimatedArguments::for_tile_animation(ctx, 0.12, 0.0, UpperLetter::POP_DURATION));
+    }
+
+    pub fn shake(&mut self, ctx: &Context) {
+        self.animate(shake_animation, AnimatedArguments::for_tile_animation(ctx, 10.0, 0.0, UpperLetter::SHAKE_DURATION));
+    }
+
+    pub fn bounce(&mut self, ctx: &Context, delay: f32) {
+        self.animate(bounce_animation, AnimatedArguments::for_tile_animation(ctx, 25.0, delay, UpperLetter::BOUNCE_DURATION));
+    }
+
     pub fn set_state(&mut self, _ctx: &mut Context, state: State) {
         self.state = state;
     }
+
+    pub fn get_state(&self) -> State {
+        self.state
+    }
     
     pub fn set_letter(&mut self, _ctx: &mut Context, typed_letter: char) {
         let letter: TextFragment = TextFragment::new(typed_letter.to_string())
-                                .color(UpperLetter::LETTER_COLOR)
-                                .scale(PxScale::from(UpperLetter::LETTER_SIZE));
+                                .color(Theme::get().letter)
+                                .scale(PxScale::from(self.get_letter_size()));
         self.letter = Some(Text::new(letter));
     }
 
+    // the letter keeps the same proportion to the tile when the tiles get smaller
+    fn get_letter_size(&self) -> f32 {
+        UpperLetter::LETTER_SIZE * self.rect.h / UpperLetter::RECT_HEIGHT
+    }
+
     pub fn clear_letter(&mut self) {
         self.letter = None;
     }
 
     pub fn get_value(&self) -> Option<char> {
-        if self.letter.is_none() {
-            return None;
-        }
-        Some(self.letter.as_ref().unwrap().fragments()[0].text.chars().next().unwrap())
+        let letter = self.letter.as_ref()?;
+        Some(letter.fragments()[0].text.chars().next().unwrap())
     }
 }
 
@@ -189,15 +214,11 @@ pub struct LowerLetter {
 impl LowerLetter {
     pub const RECT_WIDTH: f32 = 42.8;
     pub const RECT_HEIGHT: f32 = 57.6;
-    pub const RECT_DARKER_GRAY: Color = Color::new (0.145, 0.145, 0.145, 1.0);
-    pub const RECT_BRIGHTER_GRAY: Color = Color::new (0.455, 0.455, 0.455, 1.0);
-    pub const RECT_YELLOW: Color = UpperLetter::RECT_YELLOW;
-    pub const RECT_GREEN: Color = UpperLetter::RECT_GREEN;
 
     pub const LETTER_SIZE: f32 = 27.0;  // for the cases of just a single letter - its size
     pub const STRING_SIZE: f32 = 18.4;  // for the cases of strings (delete, enter) - the size of a single letter of the string
-    pub const LETTER_COLOR: Color = Color::WHITE;
 
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         _ctx: &mut Context,
         value: &String,
@@ -211,7 +232,7 @@ impl LowerLetter {
         let rect = graphics::Rect::new(offset_x, offset_y, width, height);
 
         let text: TextFragment = TextFragment::new(value)
-                                .color(LowerLetter::LETTER_COLOR)
+                                .color(Theme::get().key_letter)
                                 .scale(PxScale::from(letter_size));
 
         LowerLetter {
@@ -227,7 +248,7 @@ impl LowerLetter {
             ctx,
             graphics::DrawMode::fill(),
             self.rect,
-            State::get_color_of_lower_letter(&self.state),
+            State::get_color_of_lower_letter(&self.state, &Theme::get()),
         ).unwrap();
         canvas.draw(&rect, Vec2::new (0.0, 0.0));
         let text_width = self.text.measure(ctx).unwrap().x; 
@@ -261,7 +282,7 @@ impl LowerLetter {
 
     pub fn get_value_char(&self) -> Option<char> {
         let str = self.get_value();
-        return if str.len() > 1 {None} else {str.chars().next()}
+        if str.len() > 1 {None} else {str.chars().next()}
     }
 }
 
@@ -286,7 +307,7 @@ impl Direction {
     fn generate_direction() -> Direction {
         let mut rng = rand::thread_rng();
         let random_number = rng.gen_range(1..=4);
-        return match random_number {
+        match random_number {
             1 => Direction::Left,
             2 => Direction::Right,
             3 => Direction::Up,
@@ -321,9 +342,22 @@ impl AnimatedArguments {
         }
     }
 
+    // the animations of the tiles start after a delay and take a fixed time, both in seconds
+    pub fn for_tile_animation(ctx: &Context, change_amount: f32, delay: f32, duration: f32) -> AnimatedArguments {
+        AnimatedArguments {
+            direction: Direction::Unkwon,
+            change_amount,
+            initial_time: Some(ctx.time.time_since_start()),
+            start_moving: Some(Duration::from_secs_f32(delay)),
+            stop_moving: Some(Duration::from_secs_f32(delay + duration)),
+            lower_bound: None,
+            upper_bound: None,
+        }
+    }
+
     pub fn for_roll_animation(change_amount: f32, direction: Direction, lower_bound: f32, upper_bound: f32) -> AnimatedArguments {
         AnimatedArguments {
-            direction: direction,
+            direction,
             change_amount,
             initial_time: None,
             start_moving: None,
@@ -348,7 +382,7 @@ impl Default for AnimatedArguments {
     }
 }
 
-type AnimationFunction = fn (
+pub type AnimationFunction = fn (
     &mut AnimatedBox,
     ctx: &mut Context,
     args: &mut AnimatedArguments, 
@@ -359,7 +393,7 @@ pub fn swipe_animation(
     ctx: &mut Context,
     args: &mut AnimatedArguments,
 ) {
-    if obj.is_visible == false {
+    if !obj.is_visible {
         return;
     }
 
@@ -416,6 +450,71 @@ pub fn no_animation(
     _args: &mut AnimatedArguments,
 ) {}
 
+pub type TileAnimationFunction = fn (
+    &mut UpperLetter,
+    ctx: &mut Context,
+    args: &mut AnimatedArguments,
+);
+
+// how far a tile animation is, from 0 before it starts to 1 once it is over
+fn get_progress_of_animation(ctx: &Context, args: &AnimatedArguments) -> f32 {
+    let elapsed = ctx.time.time_since_start().saturating_sub(args.initial_time.unwrap());
+    let start_moving = args.start_moving.unwrap();
+    let stop_moving = args.stop_moving.unwrap();
+    if elapsed <= start_moving {
+        return 0.0;
+    }
+    ((elapsed - start_moving).as_secs_f32() / (stop_moving - start_moving).as_secs_f32()).min(1.0)
+}
+
+// the tile closes to a line in the first half and opens again with its new state in the second
+pub fn flip_animation(
+    obj: &mut UpperLetter,
+    ctx: &mut Context,
+    args: &mut AnimatedArguments,
+) {
+    let progress = get_progress_of_animation(ctx, args);
+    if progress < 0.5 {
+        obj.scale.y = 1.0 - progress * 2.0;
+    }
+    else {
+        if let Some(state) = obj.pending_state.take() {
+            obj.state = state;
+        }
+        obj.scale.y = progress * 2.0 - 1.0;
+    }
+}
+
+// the tile grows a bit and back when a letter is typed into it
+pub fn pop_animation(
+    obj: &mut UpperLetter,
+    ctx: &mut Context,
+    args: &mut AnimatedArguments,
+) {
+    let progress = get_progress_of_animation(ctx, args);
+    obj.scale = Vec2::splat(1.0 + args.change_amount * (PI * progress).sin());
+}
+
+// the tile moves left and right, less and less, when the word is not accepted
+pub fn shake_animation(
+    obj: &mut UpperLetter,
+    ctx: &mut Context,
+    args: &mut AnimatedArguments,
+) {
+    let progress = get_progress_of_animation(ctx, args);
+    obj.offset.x = args.change_amount * (6.0 * PI * progress).sin() * (1.0 - progress);
+}
+
+// the tile jumps up and falls back when the game is won
+pub fn bounce_animation(
+    obj: &mut UpperLetter,
+    ctx: &mut Context,
+    args: &mut AnimatedArguments,
+) {
+    let progress = get_progress_of_animation(ctx, args);
+    obj.offset.y = -args.change_amount * (PI * progress).sin();
+}
+
 #[derive(Clone)]
 pub struct AnimatedBox {
     inicial_position: Point2<f32>,
@@ -428,6 +527,7 @@ pub struct AnimatedBox {
 }
 
 impl AnimatedBox {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         offset_x: f32,
         offset_y: f32,
@@ -464,13 +564,17 @@ impl AnimatedBox {
     }
 
     pub fn is_on_screen(&self) -> bool {
-        self.is_visible == true
+        self.is_visible
     }
 
     pub fn put_on_screen(&mut self) {
         self.is_visible = true;
     }
 
+    pub fn remove_from_screen(&mut self) {
+        self.is_visible = false;
+    }
+
     pub fn draw(
         &mut self,
         ctx: &mut Context,
@@ -484,7 +588,7 @@ impl AnimatedBox {
             self.box_color,
         ).unwrap();
 
-        let _ = canvas.draw(&rect, graphics::DrawParam::default());
+        canvas.draw(&rect, graphics::DrawParam::default());
 
         let letter_width = self.text.measure(ctx).unwrap().x;
         let letter_height = self.text.measure(ctx).unwrap().y;
@@ -499,6 +603,15 @@ impl AnimatedBox {
     pub fn point_is_in(&self, x: f32, y: f32) -> bool {
         self.flating_box.contains(Point2{ x, y })
     }
+
+    // changes the text but keeps its color and size
+    pub fn set_text(&mut self, text: &str) {
+        let fragment: TextFragment = self.text.fragments()[0].clone();
+        self.text = Text::new(TextFragment {
+            text: text.to_string(),
+            ..fragment
+        });
+    }
 }
 
 
@@ -509,78 +622,84 @@ impl AnimatedBox {
 
 
 
+// the panel the statistics, the analysis and the settings are drawn on, in the middle of the window and with
+// the button that closes it in its top right corner; returns the panel and the hit box of the button
+fn draw_panel(ctx: &mut Context, canvas: &mut graphics::Canvas, theme: &Theme) -> (Rect, Rect) {
+    let width = super::super::WINDOW_WIDTH * (0.62);
+    let height = super::super::WINDOW_HEIGHT * (0.62);
+
+    let panel = Rect::new(
+        (super::super::WINDOW_WIDTH - width) / 2.0,
+        (super::super::WINDOW_HEIGHT - height) / 2.0,
+        width,
+        height
+    );
+    let background: graphics::Mesh = graphics::Mesh::new_rectangle(
+        ctx,
+        graphics::DrawMode::fill(),
+        panel,
+        theme.panel,
+    ).unwrap();
+    canvas.draw(&background, graphics::DrawParam::default());
+
+    let closing_button = Text::new(
+        TextFragment::new(String::from("x"))
+        .color(theme.closing_button)
+        .scale(PxScale::from(28.0))
+    );
+    let letter_width = closing_button.measure(ctx).unwrap().x;
+    let letter_height = closing_button.measure(ctx).unwrap().y;
+    canvas.draw(&closing_button, Vec2::new(
+        panel.x + panel.w - letter_width - 5.0,
+        panel.y + 5.0,
+    ));
+    let hit_box_of_closing_button = Rect::new(
+        panel.x + panel.w - letter_width - 5.0,
+        panel.y + 5.0,
+        letter_width,
+        letter_height,
+    );
+    (panel, hit_box_of_closing_button)
+}
+
 pub struct Statistics {
-    games_won_by_first_try: u32,
-    games_won_by_second_try: u32,
-    games_won_by_third_try: u32,
-    games_won_by_fourth_try: u32,
-    games_won_by_fifth_try: u32,
-    games_won_by_sixth_try: u32,
-    games_lost: u32,
+    stats: GameStats,
+    word_length: usize,
+    // the daily game shows the streaks of days instead of the streaks of games
+    is_daily: bool,
     is_being_shown: bool,
     hit_box_of_closing_button: graphics::Rect,
     last_guessed_by_attempt: Option<u8>,
+    data_dir: PathBuf,
 }
 
 impl Statistics {
-    pub fn new() -> Statistics {
-        let file = File::open("src/wordle/stats");
-        if file.is_err() {
-            panic!("Could not open the stats file!");
-        }
-        let mut file = file.unwrap();
-        let mut contents = String::new();
-        let _ = file.read_to_string(&mut contents);
-
-        let mut numbers = contents
-            .split_whitespace()
-            .map(|s| s.parse::<u32>().unwrap());
+    pub fn new(ctx: &Context, options: &crate::engine::GameOptions) -> Statistics {
+        let data_dir = ctx.fs.user_data_dir().to_path_buf();
+        let mut stats = GameStats::load(&data_dir, options.word_length);
 
-        drop(file);
+        if stats.games_won_by_attempt.len() < options.max_guesses {
+            stats.games_won_by_attempt.resize(options.max_guesses, 0);
+        }
 
         Statistics {
-            games_won_by_first_try: numbers.next().unwrap(),
-            games_won_by_second_try: numbers.next().unwrap(),
-            games_won_by_third_try: numbers.next().unwrap(),
-            games_won_by_fourth_try: numbers.next().unwrap(),
-            games_won_by_fifth_try: numbers.next().unwrap(),
-            games_won_by_sixth_try: numbers.next().unwrap(),
-            games_lost: numbers.next().unwrap(),
+            stats,
+            word_length: options.word_length,
+            is_daily: options.mode == GameMode::Daily,
             is_being_shown: false,
             hit_box_of_closing_button: Rect::default(),
             last_guessed_by_attempt: None,
+            data_dir,
         }
     }
 
-    fn get_games_played(&self) -> u32 {
-        self.games_won_by_first_try +
-        self.games_won_by_second_try +
-        self.games_won_by_third_try +
-        self.games_won_by_fourth_try +
-        self.games_won_by_fifth_try +
-        self.games_won_by_sixth_try +
-        self.games_lost
-    }
-
-    fn get_win_rate(&self) -> f32 {
-        let res: f32 = ((self.get_games_played() - self.games_lost) as f32 / (self.get_games_played()) as f32) * 100.0;
-        res
-    }
-
     fn get_most_wins(&self) -> u32 {
-        let wins = [
-            self.games_won_by_first_try,
-            self.games_won_by_second_try,
-            self.games_won_by_third_try,
-            self.games_won_by_fourth_try,
-            self.games_won_by_fifth_try,
-            self.games_won_by_sixth_try,
-        ];
-        let max_number = *wins.iter().max().unwrap();
-        max_number
+        // at least one so that the empty statistics do not divide by zero
+        self.stats.games_won_by_attempt.iter().copied().max().unwrap_or(0).max(1)
     }
 
     pub fn draw(&mut self, ctx: &mut Context, canvas: &mut graphics::Canvas) -> GameResult {
+        let theme = Theme::get();
         // the black background
         let bigger_rect: Rect = Rect::new(
             0.0,
@@ -589,83 +708,93 @@ impl Statistics {
             super::super::WINDOW_HEIGHT
         );
         let _f = bigger_rect.center();
-        let width = super::super::WINDOW_WIDTH as f32 * (0.62);
-        let height = super::super::WINDOW_HEIGHT as f32 * (0.62);
 
         // the actual background of the statistics
-        let smaller_rect = Rect::new(
-            (super::super::WINDOW_WIDTH - width) / 2.0,
-            (super::super::WINDOW_HEIGHT - height) / 2.0,
-            width,
-            height
-        );
-        let my_color = Color::new(0.15, 0.0, 0.23, 1.0);
+        let (smaller_rect, hit_box_of_closing_button) = draw_panel(ctx, canvas, &theme);
+        self.hit_box_of_closing_button = hit_box_of_closing_button;
         let top_most_row_height: f32 = 90.0;
         let second_row_height: f32 = 80.0;
-        let background: graphics::Mesh = graphics::Mesh::new_rectangle(
-            ctx,
-            graphics::DrawMode::fill(),
-            smaller_rect,
-            my_color,
-        ).unwrap();
-        let _ = canvas.draw(&background, graphics::DrawParam::default());
-
-        // the number of games played
-        let games_played = Text::new(
-            TextFragment::new(Self::get_games_played(&self).to_string())
-            .color(Color::WHITE)
-            .scale(PxScale::from(60.0))
-        );
-        let games_played_text_width = games_played.measure(ctx).unwrap().x;
-        let games_played_text_height = games_played.measure(ctx).unwrap().y;
-
-        // the number that represents the win rate
-        let win_rate = Text::new(
-            TextFragment::new(Self::get_win_rate(&self).round().to_string() + "%")
-            .color(Color::WHITE)
-            .scale(PxScale::from(60.0))
-        );
-        let win_rate_text_width = win_rate.measure(ctx).unwrap().x; 
-        let win_rate_text_height = win_rate.measure(ctx).unwrap().y;
 
+        // the numbers of the top row, each one above its label
+        let streaks: [(String, &str); 2] = if self.is_daily {
+            [
+                (self.stats.get_daily_streak(daily::get_today()).to_string(), "DAILY STREAK"),
+                (self.stats.max_daily_streak.to_string(), "MAX DAILY STREAK"),
+            ]
+        }
+        else {
+            [
+                (self.stats.current_streak.to_string(), "CURRENT STREAK"),
+                (self.stats.max_streak.to_string(), "MAX STREAK"),
+            ]
+        };
+        let top_numbers: [(String, &str); 5] = [
+            (self.stats.get_games_played().to_string(), "GAMES PLAYED"),
+            (self.stats.get_win_rate().round().to_string() + "%", "WIN RATE"),
+            streaks[0].clone(),
+            streaks[1].clone(),
+            (self.stats.hard_mode_wins.to_string(), "HARD MODE WINS"),
+        ];
+        let column_width = smaller_rect.w / top_numbers.len() as f32;
         let slight_offset_y: f32 = 12.0;
-        canvas.draw(&games_played, Vec2::new(
-            smaller_rect.x + (smaller_rect.w / 2.0 - games_played_text_width) / 2.0,
-            smaller_rect.y + (top_most_row_height - games_played_text_height) / 2.0 + slight_offset_y,
-        ));
-        canvas.draw(&win_rate, Vec2::new(
-            smaller_rect.x + (smaller_rect.w * (3.0 / 2.0) - win_rate_text_width) / 2.0,
-            smaller_rect.y + (top_most_row_height - win_rate_text_height) / 2.0 + slight_offset_y,
-        ));
+        for (i, (number, label)) in top_numbers.iter().enumerate() {
+            let center_of_column = smaller_rect.x + column_width * (i as f32 + 0.5);
 
-        // the actual text "GAMES PLAYED"
-        let games_played = Text::new(
-            TextFragment::new(String::from("GAMES PLAYED"))
-            .color(Color::WHITE)
-            .scale(PxScale::from(28.0))
-        );
-        let games_played_text_width = games_played.measure(ctx).unwrap().x;
-        canvas.draw(&games_played, Vec2::new(
-            smaller_rect.x + (smaller_rect.w / 2.0 - games_played_text_width) / 2.0,
-            smaller_rect.y + top_most_row_height,
-        ));
+            let number = Text::new(
+                TextFragment::new(number.clone())
+                .color(theme.letter)
+                .scale(PxScale::from(60.0))
+            );
+            let number_text_width = number.measure(ctx).unwrap().x;
+            let number_text_height = number.measure(ctx).unwrap().y;
+            canvas.draw(&number, Vec2::new(
+                center_of_column - number_text_width / 2.0,
+                smaller_rect.y + (top_most_row_height - number_text_height) / 2.0 + slight_offset_y,
+            ));
 
-        // the actual text 'WIN RATE"
-        let win_rate = Text::new(
-            TextFragment::new(String::from("WIN RATE"))
-            .color(Color::WHITE)
-            .scale(PxScale::from(28.0))
-        );
-        let win_rate_text_width = win_rate.measure(ctx).unwrap().x;
-        canvas.draw(&win_rate, Vec2::new(
-            smaller_rect.x + (smaller_rect.w * (3.0/2.0) - win_rate_text_width) / 2.0,
-            smaller_rect.y + top_most_row_height,
-        ));
+            // the label is broken into two lines and then made smaller if it does not fit in its column
+            let mut label_size: f32 = 24.0;
+            let mut label = label.to_string();
+            let mut label_text = Text::new(
+                TextFragment::new(label.clone())
+                .color(theme.letter)
+                .scale(PxScale::from(label_size))
+            );
+            if label_text.measure(ctx).unwrap().x > column_width * 0.9 {
+                let middle_space = label.char_indices()
+                    .filter(|(_, ch)| *ch == ' ')
+                    .min_by_key(|(j, _)| j.abs_diff(label.len() / 2))
+                    .map(|(j, _)| j);
+                if let Some(j) = middle_space {
+                    label.replace_range(j..j + 1, "\n");
+                }
+                label_text = Text::new(
+                    TextFragment::new(label.clone())
+                    .color(theme.letter)
+                    .scale(PxScale::from(label_size))
+                );
+            }
+            let label_text_width = label_text.measure(ctx).unwrap().x;
+            if label_text_width > column_width * 0.9 {
+                label_size *= column_width * 0.9 / label_text_width;
+                label_text = Text::new(
+                    TextFragment::new(label)
+                    .color(theme.letter)
+                    .scale(PxScale::from(label_size))
+                );
+            }
+            label_text.set_layout(graphics::TextLayout::center());
+            let label_text_height = label_text.measure(ctx).unwrap().y;
+            canvas.draw(&label_text, Vec2::new(
+                center_of_column,
+                smaller_rect.y + top_most_row_height + label_text_height / 2.0,
+            ));
+        }
 
         // the actual text "GUESS DISTRIBUTION"
         let guess_distribution = Text::new(
             TextFragment::new(String::from("GUESS DISTRIBUTION"))
-            .color(Color::WHITE)
+            .color(theme.letter)
             .scale(PxScale::from(38.0))
         );
         let text_width = guess_distribution.measure(ctx).unwrap().x;
@@ -676,23 +805,16 @@ impl Statistics {
         ));
 
         // defining variables (constants) to help draw the the ordinal number, the rectangles and the number of games won by a certain amount of guesses
+        let number_of_slices = self.stats.games_won_by_attempt.len();
         let rect_slice_height: f32 = 34.5;
         let separation_between_slices: f32 = 6.2;
         let inicial_dy: f32 = smaller_rect.y + top_most_row_height + second_row_height +
-            (smaller_rect.h - top_most_row_height - second_row_height - rect_slice_height*super::WORDLE_ROWS as f32 - separation_between_slices*(super::WORDLE_ROWS - 1) as f32) / 2.0;
+            (smaller_rect.h - top_most_row_height - second_row_height - rect_slice_height*number_of_slices as f32 - separation_between_slices*(number_of_slices - 1) as f32) / 2.0;
         let dist_from_left_or_right: f32 = 50.0;
         let width_of_smallest_rect: f32 = 30.0;
         let width_of_biggest_rect: f32 = smaller_rect.w - dist_from_left_or_right*2.0;
-        for i in 0..super::WORDLE_ROWS {
-            let curr_number_of_wins = match i {
-                0 => self.games_won_by_first_try,
-                1 => self.games_won_by_second_try,
-                2 => self.games_won_by_third_try,
-                3 => self.games_won_by_fourth_try,
-                4 => self.games_won_by_fifth_try,
-                5 => self.games_won_by_sixth_try,
-                _ => panic!("Number of rows do not match the statistics!"),
-            };
+        for i in 0..number_of_slices {
+            let curr_number_of_wins = self.stats.games_won_by_attempt[i];
 
             let curr_width = (curr_number_of_wins as f32 / self.get_most_wins() as f32) * width_of_biggest_rect;
             let curr_width: f32 = if curr_width <= width_of_smallest_rect {width_of_smallest_rect} else {curr_width};
@@ -705,8 +827,8 @@ impl Statistics {
             );
 
             let color: Color = if self.last_guessed_by_attempt.is_some() && self.last_guessed_by_attempt.unwrap() == i as u8
-             {super::LowerLetter::RECT_GREEN} 
-                else {Color::BLUE};
+             {theme.correct} 
+                else {theme.bar};
             let first_slice = graphics::Mesh::new_rectangle(
                 ctx,
                 graphics::DrawMode::fill(),
@@ -718,7 +840,7 @@ impl Statistics {
 
             let number_of_wins_as_text = Text::new(
                 TextFragment::new(curr_number_of_wins.to_string())
-                .color(Color::WHITE)
+                .color(theme.button_text)
                 .scale(PxScale::from(28.0))
             );
 
@@ -734,7 +856,7 @@ impl Statistics {
 
             let curr_number = Text::new(
                 TextFragment::new((i + 1).to_string() + ".")
-                .color(Color::WHITE)
+                .color(theme.letter)
                 .scale(PxScale::from(28.0))
             );
 
@@ -745,34 +867,120 @@ impl Statistics {
                 smaller_rect.x + (dist_from_left_or_right - letter_width) / 2.0,
                 inicial_dy + rect_slice_height * i as f32 + separation_between_slices * i as f32 + (rect_slice_height - letter_height).abs() / 2.0,
             ));
+        }
 
-            let closing_button = Text::new(
-                TextFragment::new(String::from("x"))
-                .color(Color::RED)
-                .scale(PxScale::from(28.0))
-            );
+        Ok(())
+    }
 
-            let letter_width = closing_button.measure(ctx).unwrap().x;
-            let letter_height = closing_button.measure(ctx).unwrap().y;
+    pub fn is_on_screen(&self) -> bool {
+        self.is_being_shown
+    }
 
-            canvas.draw(&closing_button, Vec2::new(
-                smaller_rect.x + smaller_rect.w - letter_width - 5.0,
-                smaller_rect.y + 5.0,
-            ));
-            let hit_box_of_closing_button: graphics::Rect = Rect::new(
-                smaller_rect.x + smaller_rect.w - letter_width - 5.0,
-                smaller_rect.y + 5.0,
-                letter_width,
-                letter_height,  
+    pub fn put_on_screen(&mut self) {
+        self.is_being_shown = true;
+    }
+
+    pub fn remove_from_screen(&mut self) {
+        self.is_being_shown = false;
+    }
+
+    pub fn quit_stats_screen_request(&self, x: f32, y: f32) -> bool {
+        self.is_being_shown && self.hit_box_of_closing_button.contains(Point2 {x, y})
+    }
+
+    // counts the game that just ended and adds it to the history
+    pub fn update_stats(&mut self, engine: &GameEngine) {
+        let attempt = (engine.get_game_state() == GameState::Won).then_some(engine.get_curr_row());
+        self.stats.record_game(attempt, engine.is_hard_mode(), engine.get_daily_date());
+        if let Some(attempt) = attempt {
+            self.last_guessed_by_attempt = Some(attempt as u8 - 1);
+        }
+
+        if let Err(err) = self.stats.save(&self.data_dir, self.word_length) {
+            eprintln!("Could not save the statistics: {}", err);
+        }
+        if let Err(err) = history::append_to_history(&self.data_dir, &GameRecord::from_engine(engine)) {
+            eprintln!("Could not save the game to the history: {}", err);
+        }
+    }
+}
+// every guess of the finished game next to what the solver would have done, drawn over the game like the statistics
+#[derive(Default)]
+pub struct Analysis {
+    rows: Vec<GuessAnalysis>,
+    is_being_shown: bool,
+    hit_box_of_closing_button: graphics::Rect,
+}
+
+impl Analysis {
+    // the solver takes its time, so a game is only analysed the first time it is asked for
+    pub fn analyze(&mut self, engine: &GameEngine) {
+        if self.rows.is_empty() {
+            self.rows = solver::analyze_game(engine, Strategy::Entropy);
+        }
+    }
+
+    pub fn draw(&mut self, ctx: &mut Context, canvas: &mut graphics::Canvas) -> GameResult {
+        let theme = Theme::get();
+        let (smaller_rect, hit_box_of_closing_button) = draw_panel(ctx, canvas, &theme);
+        self.hit_box_of_closing_button = hit_box_of_closing_button;
+
+        let title = Text::new(
+            TextFragment::new(String::from("ANALYSIS"))
+            .color(theme.letter)
+            .scale(PxScale::from(38.0))
+        );
+        let title_width = title.measure(ctx).unwrap().x;
+        canvas.draw(&title, Vec2::new(
+            smaller_rect.x + (smaller_rect.w - title_width) / 2.0,
+            smaller_rect.y + 20.0,
+        ));
+
+        // the left side of every column, as a part of the width
+        let columns: [(f32, &str); 6] = [
+            (0.05, ""),
+            (0.12, "GUESS"),
+            (0.33, "BEFORE"),
+            (0.48, "AFTER"),
+            (0.62, "BITS"),
+            (0.76, "SOLVER"),
+        ];
+        let row_height: f32 = 40.0;
+        let header_y = smaller_rect.y + 80.0;
+
+        let draw_cell = |canvas: &mut graphics::Canvas, column: usize, row: usize, text: String, color: Color| {
+            let text = Text::new(
+                TextFragment::new(text)
+                .color(color)
+                .scale(PxScale::from(24.0))
             );
-            self.hit_box_of_closing_button = hit_box_of_closing_button;
+            canvas.draw(&text, Vec2::new(
+                smaller_rect.x + smaller_rect.w * columns[column].0,
+                header_y + row_height * row as f32,
+            ));
+        };
+
+        for (column, (_, label)) in columns.iter().enumerate() {
+            draw_cell(canvas, column, 0, label.to_string(), theme.letter);
+        }
+        for (i, row) in self.rows.iter().enumerate() {
+            let best_guess: String = row.best_guess.as_ref().map(|guess| guess.iter().collect()).unwrap_or_default();
+            // the solver agreeing with the guess is shown in green
+            let color_of_best_guess = if row.best_guess.as_ref() == Some(&row.guess) { theme.correct } else { theme.letter };
+
+            draw_cell(canvas, 0, i + 1, (i + 1).to_string() + ".", theme.letter);
+            draw_cell(canvas, 1, i + 1, row.guess.iter().collect(), theme.letter);
+            draw_cell(canvas, 2, i + 1, row.candidates_before.to_string(), theme.letter);
+            draw_cell(canvas, 3, i + 1, row.candidates_after.to_string(), theme.letter);
+            draw_cell(canvas, 4, i + 1, format!("{:.2}", row.bits), theme.letter);
+            draw_cell(canvas, 5, i + 1, best_guess, color_of_best_guess);
         }
 
         Ok(())
     }
 
     pub fn is_on_screen(&self) -> bool {
-        self.is_being_shown == true
+        self.is_being_shown
     }
 
     pub fn put_on_screen(&mut self) {
@@ -783,48 +991,151 @@ impl Statistics {
         self.is_being_shown = false;
     }
 
-    pub fn quit_stats_screen_request(&self, x: f32, y: f32) -> bool {
-        self.hit_box_of_closing_button.contains(Point2 {x, y})
+    pub fn quit_analysis_screen_request(&self, x: f32, y: f32) -> bool {
+        self.is_being_shown && self.hit_box_of_closing_button.contains(Point2 {x, y})
     }
+}
+
+// one row of the settings
+#[derive(Copy, Clone, Debug)]
+#[derive(PartialEq)]
+pub enum Setting {
+    HardMode,
+    Theme,
+    WordLength,
+    Guesses,
+    Animations,
+    Sound,
+}
 
-    // if None |> Lost else |> number of guesses used
-    pub fn update_stats(&mut self, input: Option<u32>) {
-        if input.is_none() {
-            self.games_lost += 1;
+// the preferences drawn over the game like the statistics, a value changes to the next one when it is clicked
+#[derive(Default)]
+pub struct SettingsMenu {
+    is_being_shown: bool,
+    hit_box_of_closing_button: graphics::Rect,
+    hit_boxes_of_values: Vec<(Setting, graphics::Rect)>,
+}
+
+impl SettingsMenu {
+    const ROWS: [(Setting, &'static str); 6] = [
+        (Setting::HardMode, "HARD MODE"),
+        (Setting::Theme, "THEME"),
+        (Setting::WordLength, "WORD LENGTH"),
+        (Setting::Guesses, "GUESSES"),
+        (Setting::Animations, "ANIMATIONS"),
+        (Setting::Sound, "SOUND"),
+    ];
+
+    fn get_value(setting: Setting, options: &GameOptions) -> String {
+        let on_or_off = |value: bool| String::from(if value { "ON" } else { "OFF" });
+        match setting {
+            Setting::HardMode => on_or_off(options.hard_mode),
+            Setting::Theme => options.theme.to_uppercase(),
+            Setting::WordLength => options.word_length.to_string(),
+            Setting::Guesses => options.max_guesses.to_string(),
+            Setting::Animations => on_or_off(options.animations),
+            Setting::Sound => on_or_off(options.sound),
         }
-        else {
-            match input.unwrap() {
-                1 => self.games_won_by_first_try += 1,
-                2 => self.games_won_by_second_try += 1,
-                3 => self.games_won_by_third_try += 1,
-                4 => self.games_won_by_fourth_try += 1,
-                5 => self.games_won_by_fifth_try += 1,
-                6 => self.games_won_by_sixth_try += 1,
-                _ => panic!("Input to put into statistics was not valid!"),
-            }
-            self.last_guessed_by_attempt = Some(input.unwrap() as u8 - 1);
+    }
+
+    pub fn draw(&mut self, ctx: &mut Context, canvas: &mut graphics::Canvas, options: &GameOptions) -> GameResult {
+        let theme = Theme::get();
+        let (smaller_rect, hit_box_of_closing_button) = draw_panel(ctx, canvas, &theme);
+        self.hit_box_of_closing_button = hit_box_of_closing_button;
+
+        let title = Text::new(
+            TextFragment::new(String::from("SETTINGS"))
+            .color(theme.letter)
+            .scale(PxScale::from(38.0))
+        );
+        let title_width = title.measure(ctx).unwrap().x;
+        canvas.draw(&title, Vec2::new(
+            smaller_rect.x + (smaller_rect.w - title_width) / 2.0,
+            smaller_rect.y + 20.0,
+        ));
+
+        let row_height: f32 = 52.0;
+        let value_height: f32 = 40.0;
+        let first_row_y = smaller_rect.y + 90.0;
+
+        self.hit_boxes_of_values.clear();
+        for (i, (setting, label)) in SettingsMenu::ROWS.iter().enumerate() {
+            let row_y = first_row_y + row_height * i as f32;
+
+            let label = Text::new(
+                TextFragment::new(label.to_string())
+                .color(theme.letter)
+                .scale(PxScale::from(26.0))
+            );
+            let label_height = label.measure(ctx).unwrap().y;
+            canvas.draw(&label, Vec2::new(
+                smaller_rect.x + smaller_rect.w * 0.1,
+                row_y + (value_height - label_height) / 2.0,
+            ));
+
+            let value_box = Rect::new(
+                smaller_rect.x + smaller_rect.w * 0.58,
+                row_y,
+                smaller_rect.w * 0.32,
+                value_height,
+            );
+            let value_mesh: graphics::Mesh = graphics::Mesh::new_rounded_rectangle(
+                ctx,
+                graphics::DrawMode::fill(),
+                value_box,
+                5.0,
+                theme.button,
+            ).unwrap();
+            canvas.draw(&value_mesh, graphics::DrawParam::default());
+
+            let value = Text::new(
+                TextFragment::new(SettingsMenu::get_value(*setting, options))
+                .color(theme.button_text)
+                .scale(PxScale::from(22.0))
+            );
+            let value_width = value.measure(ctx).unwrap().x;
+            let value_text_height = value.measure(ctx).unwrap().y;
+            canvas.draw(&value, Vec2::new(
+                value_box.x + (value_box.w - value_width) / 2.0,
+                value_box.y + (value_box.h - value_text_height) / 2.0,
+            ));
+            self.hit_boxes_of_values.push((*setting, value_box));
         }
 
-        let mut file = OpenOptions::new()
-            .write(true)
-            .truncate(true)
-            .create(true)
-            .open("src/wordle/stats").unwrap();
-    
-        let numbers = vec![
-            self.games_won_by_first_try,
-            self.games_won_by_second_try,
-            self.games_won_by_third_try,
-            self.games_won_by_fourth_try,
-            self.games_won_by_fifth_try,
-            self.games_won_by_sixth_try,
-            self.games_lost,
-        ];
-        let numbers_str = numbers.iter()
-            .map(|n| n.to_string())
-            .collect::<Vec<String>>()
-            .join(" ");
-        
-        let _ = file.write(numbers_str.as_bytes());
+        let note = Text::new(
+            TextFragment::new(String::from("THE CHANGES APPLY FROM THE NEXT GAME"))
+            .color(theme.letter)
+            .scale(PxScale::from(18.0))
+        );
+        let note_width = note.measure(ctx).unwrap().x;
+        canvas.draw(&note, Vec2::new(
+            smaller_rect.x + (smaller_rect.w - note_width) / 2.0,
+            smaller_rect.y + smaller_rect.h - 40.0,
+        ));
+
+        Ok(())
+    }
+
+    // the setting whose value is at the point, none if the point is not on a value
+    pub fn get_clicked_setting(&self, x: f32, y: f32) -> Option<Setting> {
+        self.hit_boxes_of_values.iter()
+            .find(|(_, hit_box)| hit_box.contains(Point2 {x, y}))
+            .map(|(setting, _)| *setting)
     }
-}
\ No newline at end of file
+
+    pub fn is_on_screen(&self) -> bool {
+        self.is_being_shown
+    }
+
+    pub fn put_on_screen(&mut self) {
+        self.is_being_shown = true;
+    }
+
+    pub fn remove_from_screen(&mut self) {
+        self.is_being_shown = false;
+    }
+
+    pub fn quit_settings_screen_request(&self, x: f32, y: f32) -> bool {
+        self.is_being_shown && self.hit_box_of_closing_button.contains(Point2 {x, y})
+    }
+}
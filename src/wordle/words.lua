@@ -1,42 +1,16 @@
-FILE_NAME = "src/wordle/five_letter_words"
-NUMBER_OF_LETTERS_IN_wORD = 5;
-
-
-function get_all_words () 
-    local words = io.open (FILE_NAME);
-
-    if words == nil then
-        error("Could not open the file with the words");
-    end
-
+-- the words of the given length from the text of a word list, one word on every line;
+-- any other line is skipped, so that a replaced list may hold words of many lengths
+function get_all_words (text, number_of_letters)
     local my_table = {};
 
     local ctr = 1;
 
-    while true do 
-        curr_word = words:read(NUMBER_OF_LETTERS_IN_wORD);
-        my_table[ctr] = curr_word;
-        ctr = ctr + 1;
-        while true do   
-            check = words:read(1);
-            if check == "\n" or check == nil then
-                break
-            end
-        end
-        if check == nil then
-            break;
+    for line in string.gmatch(text, "[^\r\n]+") do
+        if string.len(line) == number_of_letters and string.match(line, "^%a+$") then
+            my_table[ctr] = string.lower(line);
+            ctr = ctr + 1;
         end
     end
 
-    words:close();
-
     return my_table, ctr - 1;
 end
-
-math.randomseed(os.time())
-
-function get_random_word ()
-    local words, max_words = get_all_words();
-    random_num = math.random(1, max_words);
-    return words[random_num];
-end